@@ -0,0 +1,332 @@
+use std::{
+    any::Any,
+    cell::RefCell,
+    collections::{BTreeMap, BTreeSet},
+    num::NonZeroUsize,
+    panic::{self, AssertUnwindSafe},
+    path::PathBuf,
+    sync::atomic::{AtomicUsize, Ordering},
+    thread,
+    time::Duration,
+};
+
+use anyhow::bail;
+use common::*;
+
+/// Workers get as much stack as the main thread, as some solutions recurse deeply.
+const WORKER_STACK: usize = 8 * 1024 * 1024;
+
+thread_local! {
+    /// The message and location of the last panic on this thread.
+    static PANIC: RefCell<Option<String>> = const { RefCell::new(None) };
+}
+
+#[derive(Debug)]
+enum Outcome {
+    Solved {
+        report: Report,
+        verdicts: Vec<Verdict>,
+    },
+    Failed(SolveError),
+    Panicked(String),
+    Skipped(InputError),
+}
+
+impl Outcome {
+    fn answer(&self, part: Part) -> &str {
+        match self {
+            Self::Solved { report, .. } => report
+                .answers
+                .iter()
+                .find(|answer| answer.part == part)
+                .map_or("-", |answer| &answer.answer),
+            _ => "-",
+        }
+    }
+
+    fn duration(&self) -> String {
+        match self {
+            Self::Solved { report, .. } => {
+                let total = report
+                    .parse
+                    .into_iter()
+                    .chain(report.answers.iter().filter_map(|answer| answer.duration));
+
+                format!("{:.2?}", total.sum::<Duration>())
+            }
+            _ => "-".to_string(),
+        }
+    }
+
+    fn status(&self) -> String {
+        match self {
+            Self::Solved { verdicts, .. } => {
+                let verdict = if verdicts
+                    .iter()
+                    .any(|verdict| matches!(verdict, Verdict::Fail { .. }))
+                {
+                    "fail"
+                } else if verdicts.contains(&Verdict::Unknown) {
+                    "unknown"
+                } else {
+                    "pass"
+                };
+
+                verdict.to_string()
+            }
+            Self::Failed(error) => format!("error: {}", error.error),
+            Self::Panicked(message) => format!("panicked: {}", message),
+            Self::Skipped(InputError::Missing { .. }) => "skipped: missing input".to_string(),
+            Self::Skipped(error) => format!("skipped: {}", error),
+        }
+    }
+}
+
+#[derive(Debug, Default)]
+struct Tally {
+    passed: usize,
+    failed: usize,
+    unknown: usize,
+    errored: usize,
+    skipped: usize,
+}
+
+impl Tally {
+    fn count(&mut self, outcome: &Outcome) {
+        match outcome {
+            Outcome::Solved { verdicts, .. } => {
+                for verdict in verdicts {
+                    match verdict {
+                        Verdict::Pass => self.passed += 1,
+                        Verdict::Fail { .. } => self.failed += 1,
+                        Verdict::Unknown => self.unknown += 1,
+                    }
+                }
+            }
+            Outcome::Failed(_) | Outcome::Panicked(_) => self.errored += 1,
+            Outcome::Skipped(_) => self.skipped += 1,
+        }
+    }
+}
+
+/// Solves every selected day on a pool of `jobs` threads, then prints a table of the results.
+pub fn all(
+    year: Option<u16>,
+    input_dir: Option<PathBuf>,
+    jobs: Option<usize>,
+) -> anyhow::Result<()> {
+    let registry = crate::registry::registry();
+    let source = input_dir.map_or(InputSource::Default, InputSource::Directory);
+
+    let solutions = registry
+        .iter()
+        .filter(|solution| year.is_none_or(|year| solution.year == year))
+        .collect::<Vec<_>>();
+
+    if solutions.is_empty() {
+        bail!("No solutions selected");
+    }
+
+    let stores = solutions
+        .iter()
+        .map(|solution| solution.year)
+        .collect::<BTreeSet<_>>()
+        .into_iter()
+        .map(|year| Ok((year, AnswerStore::load(year)?)))
+        .collect::<anyhow::Result<BTreeMap<_, _>>>()?;
+
+    let jobs = jobs
+        .or_else(|| thread::available_parallelism().ok().map(NonZeroUsize::get))
+        .unwrap_or(1)
+        .min(solutions.len());
+
+    let run = |solution: &RegisteredSolution| {
+        let input = match source.load(solution.year, solution.day) {
+            Ok(input) => input,
+            Err(error) => return Outcome::Skipped(error),
+        };
+
+        match panic::catch_unwind(AssertUnwindSafe(|| solution.solve(&input, &Part::ALL))) {
+            Ok(Ok(report)) => {
+                let store = &stores[&solution.year];
+                let verdicts = report
+                    .answers
+                    .iter()
+                    .map(|answer| store.verify(solution.day, answer.part, &answer.answer))
+                    .collect();
+
+                Outcome::Solved { report, verdicts }
+            }
+            Ok(Err(error)) => Outcome::Failed(error),
+            Err(payload) => Outcome::Panicked(
+                PANIC
+                    .take()
+                    .unwrap_or_else(|| panic_message(payload.as_ref())),
+            ),
+        }
+    };
+
+    // Panics are reported in the table rather than as they happen.
+    let hook = panic::take_hook();
+    panic::set_hook(Box::new(|info| {
+        let message = panic_message(info.payload());
+        let message = match info.location() {
+            Some(location) => format!("{} at {}", message, location),
+            None => message,
+        };
+
+        PANIC.set(Some(message));
+    }));
+
+    let outcomes = run_pool(&solutions, jobs, |solution| run(solution));
+    panic::set_hook(hook);
+
+    let mut tally = Tally::default();
+    let mut rows =
+        vec![["Year", "Day", "Part One", "Part Two", "Time", "Status"].map(String::from)];
+
+    for (solution, outcome) in solutions.iter().zip(&outcomes) {
+        tally.count(outcome);
+        rows.push([
+            solution.year.to_string(),
+            solution.day.to_string(),
+            outcome.answer(Part::One).to_string(),
+            outcome.answer(Part::Two).to_string(),
+            outcome.duration(),
+            outcome.status(),
+        ]);
+    }
+
+    print!("{}", table(&rows));
+    println!(
+        "\n{} passed, {} failed, {} unknown, {} errored, {} skipped",
+        tally.passed, tally.failed, tally.unknown, tally.errored, tally.skipped
+    );
+
+    if tally.failed > 0 || tally.errored > 0 {
+        bail!(
+            "{} answers failed verification and {} days errored",
+            tally.failed,
+            tally.errored
+        );
+    }
+
+    Ok(())
+}
+
+/// Runs `f` on every item using `jobs` threads, returning the results in the original order.
+fn run_pool<T: Sync, R: Send>(items: &[T], jobs: usize, f: impl Fn(&T) -> R + Sync) -> Vec<R> {
+    let next = AtomicUsize::new(0);
+
+    let mut results = thread::scope(|scope| {
+        let workers = (0..jobs.max(1))
+            .map(|_| {
+                thread::Builder::new()
+                    .stack_size(WORKER_STACK)
+                    .spawn_scoped(scope, || {
+                        let mut results = Vec::new();
+
+                        loop {
+                            let index = next.fetch_add(1, Ordering::Relaxed);
+                            let Some(item) = items.get(index) else {
+                                break results;
+                            };
+
+                            results.push((index, f(item)));
+                        }
+                    })
+                    .expect("Failed to spawn worker")
+            })
+            .collect::<Vec<_>>();
+
+        workers
+            .into_iter()
+            .flat_map(|worker| worker.join().expect("Worker panicked"))
+            .collect::<Vec<_>>()
+    });
+
+    results.sort_by_key(|(index, _)| *index);
+    results.into_iter().map(|(_, result)| result).collect()
+}
+
+fn panic_message(payload: &(dyn Any + Send)) -> String {
+    if let Some(message) = payload.downcast_ref::<&str>() {
+        message.to_string()
+    } else if let Some(message) = payload.downcast_ref::<String>() {
+        message.clone()
+    } else {
+        "unknown panic".to_string()
+    }
+}
+
+/// Left-aligned columns separated by two spaces, with a rule under the first row.
+fn table<const N: usize>(rows: &[[String; N]]) -> String {
+    let mut widths = [0; N];
+
+    for row in rows {
+        for (width, cell) in widths.iter_mut().zip(row) {
+            *width = (*width).max(cell.chars().count());
+        }
+    }
+
+    let mut output = String::new();
+
+    for (index, row) in rows.iter().enumerate() {
+        let line = row
+            .iter()
+            .zip(widths)
+            .map(|(cell, width)| format!("{:width$}", cell, width = width))
+            .collect::<Vec<_>>()
+            .join("  ");
+
+        output.push_str(line.trim_end());
+        output.push('\n');
+
+        if index == 0 {
+            let rule = widths.map(|width| "-".repeat(width)).join("  ");
+            output.push_str(&rule);
+            output.push('\n');
+        }
+    }
+
+    output
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn pool_order() {
+        let items = (0..50).collect::<Vec<u64>>();
+        let results = run_pool(&items, 4, |&item| {
+            thread::sleep(Duration::from_micros(50 - item));
+            item * 2
+        });
+
+        assert_eq!(results, (0..50).map(|item| item * 2).collect::<Vec<_>>());
+    }
+
+    #[test]
+    fn format_table() {
+        let rows = [
+            ["Day", "Answer"].map(String::from),
+            ["1", "12345"].map(String::from),
+            ["10", "7"].map(String::from),
+        ];
+
+        assert_eq!(
+            table(&rows),
+            "Day  Answer\n---  ------\n1    12345\n10   7\n"
+        );
+    }
+
+    #[test]
+    fn payloads() {
+        let payload = panic::catch_unwind(|| panic!("{} went wrong", 2)).unwrap_err();
+        assert_eq!(panic_message(payload.as_ref()), "2 went wrong");
+
+        let payload = panic::catch_unwind(|| panic!("static")).unwrap_err();
+        assert_eq!(panic_message(payload.as_ref()), "static");
+    }
+}
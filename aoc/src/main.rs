@@ -0,0 +1,231 @@
+mod all;
+mod registry;
+mod scaffold;
+mod verify;
+
+use std::{io, path::PathBuf};
+
+use anyhow::{bail, Context};
+use clap::{Parser, Subcommand};
+use common::{
+    image,
+    viz::{self, Playback},
+    *,
+};
+
+#[derive(Debug, Parser)]
+#[command(about = "Runs Advent of Code solutions")]
+struct Cli {
+    #[command(subcommand)]
+    command: Command,
+}
+
+#[derive(Debug, Subcommand)]
+enum Command {
+    /// Run the solution for a day, or every day of a year
+    Run {
+        #[command(flatten)]
+        selection: Selection,
+        #[command(flatten)]
+        input: InputArgs,
+        /// One of text, json or csv [env: AOC_OUTPUT_FORMAT]
+        #[arg(long)]
+        format: Option<OutputFormat>,
+        /// Draw simulations to stderr at FPS frames per second, or one per enter press with
+        /// `step` [env: AOC_VISUALIZE]
+        #[arg(long, value_name = "FPS", num_args = 0..=1, default_missing_value = "10")]
+        visualize: Option<Playback>,
+        /// Save images of simulations into `<DIR>/<YEAR>-day-<DAY>` [env: AOC_EXPORT_DIR]
+        #[arg(long, value_name = "DIR")]
+        export: Option<PathBuf>,
+    },
+    /// Time each part over many iterations
+    Bench {
+        #[command(flatten)]
+        selection: Selection,
+        #[command(flatten)]
+        input: InputArgs,
+        /// How many times to run each part
+        #[arg(long, short = 'n', default_value_t = 100)]
+        iterations: u32,
+    },
+    /// Check answers against each year's `answers.toml`
+    Verify {
+        /// Defaults to every year
+        year: Option<u16>,
+        /// Defaults to every solved day of the year
+        #[arg(requires = "year")]
+        day: Option<u8>,
+        /// Read inputs from `<DIR>/<YEAR>/day-<DAY>` [env: AOC_INPUT_DIR]
+        #[arg(long)]
+        input_dir: Option<PathBuf>,
+    },
+    /// Solve every registered day in parallel and check the answers
+    All {
+        /// Defaults to every year
+        year: Option<u16>,
+        /// Read inputs from `<DIR>/<YEAR>/day-<DAY>` [env: AOC_INPUT_DIR]
+        #[arg(long)]
+        input_dir: Option<PathBuf>,
+        /// How many days to solve at once, defaulting to one per core
+        #[arg(long, short = 'j', value_parser = clap::value_parser!(u16).range(1..))]
+        jobs: Option<u16>,
+    },
+    /// Create a crate for a new day and register it
+    New {
+        #[arg(value_parser = clap::value_parser!(u16).range(2015..))]
+        year: u16,
+        #[arg(value_parser = clap::value_parser!(u8).range(1..=25))]
+        day: u8,
+    },
+}
+
+#[derive(Debug, clap::Args)]
+struct Selection {
+    year: u16,
+    #[arg(required_unless_present = "all")]
+    day: Option<u8>,
+    /// Select every solved day of the year
+    #[arg(long, conflicts_with = "day")]
+    all: bool,
+    /// Only select a single part
+    #[arg(long, value_parser = clap::value_parser!(u8).range(1..=2))]
+    part: Option<u8>,
+}
+
+impl Selection {
+    fn parts(&self) -> Vec<Part> {
+        match self.part.and_then(Part::from_number) {
+            Some(part) => vec![part],
+            None => Part::ALL.to_vec(),
+        }
+    }
+
+    fn solutions<'a>(&self, registry: &'a Registry) -> anyhow::Result<Vec<&'a RegisteredSolution>> {
+        let solutions = if self.all {
+            registry.year(self.year).collect::<Vec<_>>()
+        } else {
+            let day = self.day.context("Missing day")?;
+            let solution = registry
+                .get(self.year, day)
+                .with_context(|| format!("No solution for {} Day {}", self.year, day))?;
+            vec![solution]
+        };
+
+        if solutions.is_empty() {
+            bail!("No solutions for {}", self.year);
+        }
+
+        Ok(solutions)
+    }
+}
+
+#[derive(Debug, clap::Args)]
+struct InputArgs {
+    /// Read the input from a file, or from stdin with `-`
+    #[arg(long, conflicts_with_all = ["all", "input_dir"])]
+    input: Option<String>,
+    /// Read inputs from `<DIR>/<YEAR>/day-<DAY>` [env: AOC_INPUT_DIR]
+    #[arg(long)]
+    input_dir: Option<PathBuf>,
+}
+
+impl InputArgs {
+    fn source(self) -> InputSource {
+        match (self.input, self.input_dir) {
+            (Some(input), _) => InputSource::from_arg(&input),
+            (None, Some(directory)) => InputSource::Directory(directory),
+            (None, None) => InputSource::Default,
+        }
+    }
+}
+
+fn for_each_solution(
+    selection: &Selection,
+    input: InputArgs,
+    mut f: impl FnMut(&RegisteredSolution, &str, &[Part]) -> anyhow::Result<()>,
+) -> anyhow::Result<()> {
+    let registry = registry::registry();
+    let source = input.source();
+    let parts = selection.parts();
+
+    for solution in selection.solutions(&registry)? {
+        let input = source.load(solution.year, solution.day)?;
+        f(solution, &input, &parts)?;
+    }
+
+    Ok(())
+}
+
+fn main() -> anyhow::Result<()> {
+    let cli = Cli::parse();
+
+    match cli.command {
+        Command::Run {
+            selection,
+            input,
+            format,
+            visualize,
+            export,
+        } => {
+            let format = match format {
+                Some(format) => format,
+                None => OutputFormat::from_env()?,
+            };
+
+            let playback = match visualize {
+                Some(playback) => Some(playback),
+                None => Playback::from_env()?,
+            };
+
+            if let Some(playback) = playback {
+                viz::enable(playback);
+            }
+
+            let export = export.or_else(image::export_dir_from_env);
+            let mut writer = ReportWriter::new(io::stdout().lock(), format);
+
+            let result = for_each_solution(&selection, input, |solution, input, parts| {
+                if let Some(directory) = &export {
+                    let directory = image::day_directory(directory, solution.year, solution.day);
+                    image::export_to(Some(directory));
+                }
+
+                writer.write(&solution.solve(input, parts)?)?;
+                Ok(())
+            });
+
+            // Close the output even after a failure so what was written stays valid.
+            writer.finish()?;
+            result
+        }
+        Command::Bench {
+            selection,
+            input,
+            iterations,
+        } => {
+            let mut first = true;
+
+            for_each_solution(&selection, input, |solution, input, parts| {
+                if !first {
+                    println!();
+                }
+
+                first = false;
+                println!("{}", solution.bench(input, parts, iterations)?);
+                Ok(())
+            })
+        }
+        Command::Verify {
+            year,
+            day,
+            input_dir,
+        } => verify::verify(year, day, input_dir),
+        Command::All {
+            year,
+            input_dir,
+            jobs,
+        } => all::all(year, input_dir, jobs.map(usize::from)),
+        Command::New { year, day } => scaffold::new_day(year, day),
+    }
+}
@@ -0,0 +1,27 @@
+use common::Registry;
+
+pub fn registry() -> Registry {
+    let mut registry = Registry::new();
+
+    aoc_2015_day_1::register(&mut registry);
+    aoc_2022_day_3::register(&mut registry);
+    aoc_2022_day_6::register(&mut registry);
+    aoc_2024_day_1::register(&mut registry);
+    aoc_2024_day_2::register(&mut registry);
+    aoc_2024_day_3::register(&mut registry);
+    aoc_2024_day_4::register(&mut registry);
+    aoc_2024_day_5::register(&mut registry);
+    aoc_2024_day_6::register(&mut registry);
+    aoc_2024_day_7::register(&mut registry);
+    aoc_2024_day_8::register(&mut registry);
+    aoc_2024_day_9::register(&mut registry);
+    aoc_2024_day_10::register(&mut registry);
+    aoc_2024_day_11::register(&mut registry);
+    aoc_2024_day_12::register(&mut registry);
+    aoc_2024_day_13::register(&mut registry);
+    aoc_2024_day_14::register(&mut registry);
+    aoc_2024_day_15::register(&mut registry);
+    aoc_2025_day_1::register(&mut registry);
+
+    registry
+}
@@ -0,0 +1,258 @@
+use std::{fs, path::Path};
+
+use anyhow::{bail, Context};
+use common::*;
+
+const WORKSPACE: &str = include_str!("../templates/workspace.toml");
+const MANIFEST: &str = include_str!("../templates/Cargo.toml.template");
+const LIB: &str = include_str!("../templates/lib.rs.template");
+const MAIN: &str = include_str!("../templates/main.rs.template");
+const BUILD: &str = include_str!("../templates/build.rs.template");
+const EXAMPLE: &str = include_str!("../templates/example.toml");
+
+const ANSWERS: &str = "# Verified puzzle answers, keyed by day then part.\n";
+
+/// Creates `years/<year>/days/day-<day>`, registering it with the year workspace and the runner.
+pub fn new_day(year: u16, day: u8) -> anyhow::Result<()> {
+    let year_directory = year_directory(year);
+    let day_directory = year_directory.join("days").join(format!("day-{}", day));
+
+    if day_directory.exists() {
+        bail!("{} already exists", day_directory.display());
+    }
+
+    let workspace_path = year_directory.join("Cargo.toml");
+
+    if !workspace_path.exists() {
+        fs::create_dir_all(&year_directory)?;
+        fs::write(&workspace_path, WORKSPACE)?;
+        fs::write(AnswerStore::path(year), ANSWERS)?;
+        println!("Created the {} workspace", year);
+    }
+
+    let workspace = read(&workspace_path)?;
+    let edition = workspace_edition(&workspace)?;
+    fs::write(&workspace_path, add_member(&workspace, day)?)?;
+
+    let render = |template: &str| {
+        template
+            .replace("{{year}}", &year.to_string())
+            .replace("{{day}}", &day.to_string())
+            .replace("{{edition}}", &edition)
+    };
+
+    fs::create_dir_all(day_directory.join("src"))?;
+    fs::write(day_directory.join("Cargo.toml"), render(MANIFEST))?;
+    fs::write(day_directory.join("build.rs"), BUILD)?;
+    fs::write(day_directory.join("src").join("lib.rs"), render(LIB))?;
+    fs::write(day_directory.join("src").join("main.rs"), render(MAIN))?;
+
+    fs::create_dir_all(day_directory.join("examples"))?;
+    fs::write(day_directory.join("examples").join("example.toml"), EXAMPLE)?;
+
+    let input = day_directory.join("input");
+    fs::write(&input, "")?;
+
+    register(year, day)?;
+
+    println!(
+        "Created {} Day {} at {}",
+        year,
+        day,
+        day_directory.display()
+    );
+    println!("Save your puzzle input to {}", input.display());
+
+    Ok(())
+}
+
+fn read(path: &Path) -> anyhow::Result<String> {
+    fs::read_to_string(path).with_context(|| format!("Failed to read {}", path.display()))
+}
+
+/// Days inherit the workspace edition when it has one; older years pin 2021 per day.
+fn workspace_edition(workspace: &str) -> anyhow::Result<String> {
+    let workspace = workspace.parse::<toml::Table>()?;
+    let inherited = workspace
+        .get("workspace")
+        .and_then(|workspace| workspace.get("package"))
+        .and_then(|package| package.get("edition"))
+        .is_some();
+
+    Ok(if inherited {
+        "edition.workspace = true".to_string()
+    } else {
+        "edition = \"2021\"".to_string()
+    })
+}
+
+/// Rewrites `members` one entry per line, sorted by day, with `days/day-<day>` added.
+fn add_member(workspace: &str, day: u8) -> anyhow::Result<String> {
+    let table = workspace.parse::<toml::Table>()?;
+    let mut members = table
+        .get("workspace")
+        .and_then(|workspace| workspace.get("members"))
+        .and_then(|members| members.as_array())
+        .context("Workspace has no members list")?
+        .iter()
+        .map(|member| member.as_str().map(str::to_string))
+        .collect::<Option<Vec<_>>>()
+        .context("Workspace members must be strings")?;
+
+    let member = format!("days/day-{}", day);
+
+    if !members.contains(&member) {
+        members.push(member);
+    }
+
+    members.sort_by_key(|member| numbers(member));
+
+    let start = workspace.find("members").context("Missing members")?;
+    let end = start
+        + workspace[start..]
+            .find(']')
+            .context("Unterminated members list")?;
+
+    let mut list = "members = [\n".to_string();
+
+    for member in members {
+        list.push_str(&format!("    \"{}\",\n", member));
+    }
+
+    list.push(']');
+
+    Ok(format!(
+        "{}{}{}",
+        &workspace[..start],
+        list,
+        &workspace[end + 1..]
+    ))
+}
+
+fn register(year: u16, day: u8) -> anyhow::Result<()> {
+    let runner = Path::new(env!("CARGO_MANIFEST_DIR"));
+
+    let manifest_path = runner.join("Cargo.toml");
+    let manifest = insert_sorted(
+        &read(&manifest_path)?,
+        "aoc-",
+        &format!(
+            "aoc-{}-day-{} = {{ path = \"../years/{}/days/day-{}\" }}",
+            year, day, year, day
+        ),
+    );
+    fs::write(manifest_path, manifest)?;
+
+    let registry_path = runner.join("src").join("registry.rs");
+    let registry = insert_sorted(
+        &read(&registry_path)?,
+        "    aoc_",
+        &format!("    aoc_{}_day_{}::register(&mut registry);", year, day),
+    );
+    fs::write(registry_path, registry)?;
+
+    Ok(())
+}
+
+/// Inserts `line` among the lines starting with `prefix`, ordered by the numbers in them.
+fn insert_sorted(text: &str, prefix: &str, line: &str) -> String {
+    let mut lines = text.lines().collect::<Vec<_>>();
+
+    if lines.contains(&line) {
+        return text.to_string();
+    }
+
+    let entries = lines
+        .iter()
+        .enumerate()
+        .filter(|(_, existing)| existing.starts_with(prefix))
+        .map(|(i, _)| i)
+        .collect::<Vec<_>>();
+
+    let position = entries
+        .iter()
+        .find(|&&i| numbers(lines[i]) > numbers(line))
+        .copied()
+        .or(entries.last().map(|i| i + 1))
+        .unwrap_or(lines.len());
+
+    lines.insert(position, line);
+
+    let mut text = lines.join("\n");
+    text.push('\n');
+    text
+}
+
+fn numbers(value: &str) -> Vec<u32> {
+    value
+        .split(|c: char| !c.is_ascii_digit())
+        .filter_map(|number| number.parse().ok())
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn normalize_members() {
+        let workspace = "[workspace]\nresolver = \"2\"\nmembers = [\n    \"days/day-2\",\n    \
+                         \"days/day-13\", \"days/day-14\",\n]\n\n[workspace.package]\n";
+
+        assert_eq!(
+            add_member(workspace, 3).unwrap(),
+            "[workspace]\nresolver = \"2\"\nmembers = [\n    \"days/day-2\",\n    \
+             \"days/day-3\",\n    \"days/day-13\",\n    \"days/day-14\",\n]\n\n\
+             [workspace.package]\n"
+        );
+    }
+
+    #[test]
+    fn empty_members() {
+        assert_eq!(
+            add_member(WORKSPACE, 1).unwrap(),
+            WORKSPACE.replace("members = [\n]", "members = [\n    \"days/day-1\",\n]")
+        );
+    }
+
+    #[test]
+    fn edition() {
+        assert_eq!(
+            workspace_edition(WORKSPACE).unwrap(),
+            "edition.workspace = true"
+        );
+        assert_eq!(
+            workspace_edition("[workspace]\nmembers = []\n").unwrap(),
+            "edition = \"2021\""
+        );
+    }
+
+    #[test]
+    fn insert_registration() {
+        let registry = "fn registry() {\n    aoc_2015_day_1::register(&mut registry);\n    \
+                        aoc_2024_day_9::register(&mut registry);\n    \
+                        aoc_2024_day_10::register(&mut registry);\n\n    registry\n}\n";
+
+        let inserted = insert_sorted(
+            registry,
+            "    aoc_",
+            "    aoc_2024_day_2::register(&mut registry);",
+        );
+
+        assert_eq!(
+            inserted,
+            "fn registry() {\n    aoc_2015_day_1::register(&mut registry);\n    \
+             aoc_2024_day_2::register(&mut registry);\n    \
+             aoc_2024_day_9::register(&mut registry);\n    \
+             aoc_2024_day_10::register(&mut registry);\n\n    registry\n}\n"
+        );
+
+        let appended = insert_sorted(
+            registry,
+            "    aoc_",
+            "    aoc_2026_day_1::register(&mut registry);",
+        );
+
+        assert!(appended.contains("day_10::register(&mut registry);\n    aoc_2026_day_1"));
+    }
+}
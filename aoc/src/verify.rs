@@ -0,0 +1,100 @@
+use std::{
+    collections::{btree_map::Entry, BTreeMap},
+    path::PathBuf,
+};
+
+use anyhow::bail;
+use common::*;
+
+#[derive(Debug, Default)]
+struct Tally {
+    passed: usize,
+    failed: usize,
+    unknown: usize,
+    skipped: usize,
+}
+
+pub fn verify(
+    year: Option<u16>,
+    day: Option<u8>,
+    input_dir: Option<PathBuf>,
+) -> anyhow::Result<()> {
+    let registry = crate::registry::registry();
+    let source = input_dir.map_or(InputSource::Default, InputSource::Directory);
+    let mut tally = Tally::default();
+
+    let solutions = registry
+        .iter()
+        .filter(|solution| year.is_none_or(|year| solution.year == year))
+        .filter(|solution| day.is_none_or(|day| solution.day == day))
+        .collect::<Vec<_>>();
+
+    if solutions.is_empty() {
+        bail!("No solutions selected");
+    }
+
+    let mut stores = BTreeMap::new();
+
+    for solution in solutions {
+        let store = match stores.entry(solution.year) {
+            Entry::Occupied(entry) => entry.into_mut(),
+            Entry::Vacant(entry) => entry.insert(AnswerStore::load(solution.year)?),
+        };
+
+        let input = match source.load(solution.year, solution.day) {
+            Ok(input) => input,
+            Err(error) => {
+                println!(
+                    "{} Day {}: skipped ({})",
+                    solution.year, solution.day, error
+                );
+                tally.skipped += 1;
+                continue;
+            }
+        };
+
+        let report = match solution.solve(&input, &Part::ALL) {
+            Ok(report) => report,
+            Err(error) => {
+                println!("{}", error);
+                tally.failed += 1;
+                continue;
+            }
+        };
+
+        for answer in report.answers {
+            let verdict = store.verify(solution.day, answer.part, &answer.answer);
+
+            let detail = match &verdict {
+                Verdict::Pass => {
+                    tally.passed += 1;
+                    String::new()
+                }
+                Verdict::Fail { expected } => {
+                    tally.failed += 1;
+                    format!(" (expected {}, got {})", expected, answer.answer)
+                }
+                Verdict::Unknown => {
+                    tally.unknown += 1;
+                    format!(" ({})", answer.answer)
+                }
+            };
+
+            println!(
+                "{} Day {} Part {}: {}{}",
+                solution.year, solution.day, answer.part, verdict, detail
+            );
+        }
+    }
+
+    println!(
+        "\n{} passed, {} failed, {} unknown, {} skipped",
+        tally.passed, tally.failed, tally.unknown, tally.skipped
+    );
+
+    if tally.failed > 0 {
+        bail!("{} answers failed verification", tally.failed);
+    }
+
+    Ok(())
+}
@@ -0,0 +1,207 @@
+use std::{
+    collections::BTreeMap,
+    error::Error,
+    fmt::Display,
+    fs, io,
+    path::{Path, PathBuf},
+};
+
+use crate::{input::year_directory, Part};
+
+#[derive(Debug)]
+pub enum AnswersError {
+    Read { path: PathBuf, source: io::Error },
+    Parse { path: PathBuf, message: String },
+}
+
+impl Display for AnswersError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Read { path, source } => {
+                write!(f, "Failed to read answers {}: {}", path.display(), source)
+            }
+            Self::Parse { path, message } => {
+                write!(f, "Invalid answers {}: {}", path.display(), message)
+            }
+        }
+    }
+}
+
+impl Error for AnswersError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match self {
+            Self::Read { source, .. } => Some(source),
+            Self::Parse { .. } => None,
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Verdict {
+    Pass,
+    Fail { expected: String },
+    Unknown,
+}
+
+impl Display for Verdict {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Pass => write!(f, "pass"),
+            Self::Fail { .. } => write!(f, "fail"),
+            Self::Unknown => write!(f, "unknown"),
+        }
+    }
+}
+
+/// `one` or `two`, as parts are keyed in answer files.
+pub(crate) fn part_from_key(key: &str) -> Option<Part> {
+    match key {
+        "one" => Some(Part::One),
+        "two" => Some(Part::Two),
+        _ => None,
+    }
+}
+
+/// Answers may be written as integers or strings.
+pub(crate) fn answer_from_value(value: &toml::Value) -> Option<String> {
+    match value {
+        toml::Value::Integer(answer) => Some(answer.to_string()),
+        toml::Value::String(answer) => Some(answer.clone()),
+        _ => None,
+    }
+}
+
+/// Known answers for one year, read from `years/<year>/answers.toml`:
+///
+/// ```toml
+/// [11]
+/// one = 194557
+/// two = 231532558973909
+/// ```
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct AnswerStore {
+    answers: BTreeMap<(u8, Part), String>,
+}
+
+impl AnswerStore {
+    pub fn path(year: u16) -> PathBuf {
+        year_directory(year).join("answers.toml")
+    }
+
+    /// A year without an answers file has no known answers.
+    pub fn load(year: u16) -> Result<Self, AnswersError> {
+        let path = Self::path(year);
+
+        if path.exists() {
+            Self::load_from(&path)
+        } else {
+            Ok(Self::default())
+        }
+    }
+
+    pub fn load_from(path: &Path) -> Result<Self, AnswersError> {
+        let raw = fs::read_to_string(path).map_err(|source| AnswersError::Read {
+            path: path.to_path_buf(),
+            source,
+        })?;
+
+        Self::parse(&raw).map_err(|message| AnswersError::Parse {
+            path: path.to_path_buf(),
+            message,
+        })
+    }
+
+    fn parse(raw: &str) -> Result<Self, String> {
+        let table = raw
+            .parse::<toml::Table>()
+            .map_err(|error| error.to_string())?;
+        let mut answers = BTreeMap::new();
+
+        for (day_key, parts) in table {
+            let day = day_key
+                .parse::<u8>()
+                .map_err(|_| format!("Day must be a number: {}", day_key))?;
+            let parts = parts
+                .as_table()
+                .ok_or_else(|| format!("Day {} must be a table", day))?;
+
+            for (part_key, answer) in parts {
+                let part = part_from_key(part_key)
+                    .ok_or_else(|| format!("Unknown part for day {}: {}", day, part_key))?;
+                let answer = answer_from_value(answer)
+                    .ok_or_else(|| format!("Unsupported answer for day {}: {}", day, answer))?;
+
+                answers.insert((day, part), answer);
+            }
+        }
+
+        Ok(Self { answers })
+    }
+
+    pub fn expected(&self, day: u8, part: Part) -> Option<&str> {
+        self.answers.get(&(day, part)).map(String::as_str)
+    }
+
+    pub fn verify(&self, day: u8, part: Part, answer: &str) -> Verdict {
+        match self.expected(day, part) {
+            Some(expected) if expected == answer => Verdict::Pass,
+            Some(expected) => Verdict::Fail {
+                expected: expected.to_string(),
+            },
+            None => Verdict::Unknown,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const ANSWERS: &str = "
+[1]
+one = 74
+two = 1795
+
+[12]
+one = \"ABC\"
+";
+
+    #[test]
+    fn parse() {
+        let store = AnswerStore::parse(ANSWERS).unwrap();
+
+        assert_eq!(store.expected(1, Part::One), Some("74"));
+        assert_eq!(store.expected(1, Part::Two), Some("1795"));
+        assert_eq!(store.expected(12, Part::One), Some("ABC"));
+        assert_eq!(store.expected(12, Part::Two), None);
+    }
+
+    #[test]
+    fn verify() {
+        let store = AnswerStore::parse(ANSWERS).unwrap();
+
+        assert_eq!(store.verify(1, Part::One, "74"), Verdict::Pass);
+        assert_eq!(
+            store.verify(1, Part::Two, "1794"),
+            Verdict::Fail {
+                expected: "1795".to_string()
+            }
+        );
+        assert_eq!(store.verify(12, Part::Two, "1"), Verdict::Unknown);
+        assert_eq!(store.verify(2, Part::One, "1"), Verdict::Unknown);
+    }
+
+    #[test]
+    fn parse_invalid() {
+        assert!(AnswerStore::parse("[day-1]\none = 1").is_err());
+        assert!(AnswerStore::parse("[1]\nthree = 1").is_err());
+        assert!(AnswerStore::parse("[1]\none = 1.5").is_err());
+    }
+
+    #[test]
+    fn repository_answers() {
+        for year in [2015, 2022, 2024, 2025] {
+            AnswerStore::load(year).unwrap();
+        }
+    }
+}
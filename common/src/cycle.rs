@@ -0,0 +1,209 @@
+use std::{collections::HashMap, hash::Hash};
+
+/// Where a sequence of states starts repeating itself.
+///
+/// State `start` is the first one seen again, `length` steps later, and every state from there
+/// on repeats with that period.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Cycle {
+    pub start: usize,
+    pub length: usize,
+}
+
+impl Cycle {
+    /// The earliest step with the same state as step `n`.
+    pub fn reduce(&self, n: usize) -> usize {
+        if n < self.start {
+            n
+        } else {
+            self.start + (n - self.start) % self.length
+        }
+    }
+}
+
+/// Finds the cycle in the states reached from `initial` by repeatedly calling `step`, or `None`
+/// if `step` runs out of states first.
+///
+/// Remembers every state, so it only needs to run through the sequence once. Use [`brent`] or
+/// [`floyd`] when states are too large to keep around.
+pub fn find_cycle<T: Clone + Eq + Hash>(
+    initial: T,
+    mut step: impl FnMut(&T) -> Option<T>,
+) -> Option<Cycle> {
+    let mut seen = HashMap::new();
+    let mut state = initial;
+
+    for index in 0.. {
+        if let Some(start) = seen.insert(state.clone(), index) {
+            return Some(Cycle {
+                start,
+                length: index - start,
+            });
+        }
+
+        state = step(&state)?;
+    }
+
+    unreachable!()
+}
+
+/// Like [`find_cycle`], using Floyd's tortoise and hare in constant memory.
+pub fn floyd<T: Clone + Eq>(initial: T, mut step: impl FnMut(&T) -> Option<T>) -> Option<Cycle> {
+    let mut tortoise = step(&initial)?;
+    let mut hare = step(&tortoise)?;
+
+    while tortoise != hare {
+        tortoise = step(&tortoise)?;
+        hare = step(&hare)?;
+        hare = step(&hare)?;
+    }
+
+    // The hare is now a multiple of the cycle length ahead, so stepping both from the initial
+    // state and from the meeting point lines them up at the start of the cycle.
+    let mut start = 0;
+    tortoise = initial;
+
+    while tortoise != hare {
+        tortoise = step(&tortoise)?;
+        hare = step(&hare)?;
+        start += 1;
+    }
+
+    let mut length = 1;
+    hare = step(&tortoise)?;
+
+    while tortoise != hare {
+        hare = step(&hare)?;
+        length += 1;
+    }
+
+    Some(Cycle { start, length })
+}
+
+/// Like [`floyd`], using Brent's algorithm, which calls `step` fewer times.
+pub fn brent<T: Clone + Eq>(initial: T, mut step: impl FnMut(&T) -> Option<T>) -> Option<Cycle> {
+    let mut power = 1;
+    let mut length = 1;
+    let mut tortoise = initial.clone();
+    let mut hare = step(&initial)?;
+
+    while tortoise != hare {
+        if power == length {
+            tortoise = hare.clone();
+            power *= 2;
+            length = 0;
+        }
+
+        hare = step(&hare)?;
+        length += 1;
+    }
+
+    let mut start = 0;
+    tortoise = initial.clone();
+    hare = initial;
+
+    for _ in 0..length {
+        hare = step(&hare)?;
+    }
+
+    while tortoise != hare {
+        tortoise = step(&tortoise)?;
+        hare = step(&hare)?;
+        start += 1;
+    }
+
+    Some(Cycle { start, length })
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::HashSet;
+
+    use proptest::prelude::*;
+
+    use super::*;
+
+    /// 0 → 1 → 2 → 3 → 4 → 5 → 2.
+    fn rho(&state: &u8) -> Option<u8> {
+        Some(if state == 5 { 2 } else { state + 1 })
+    }
+
+    #[test]
+    fn known_cycle() {
+        let expected = Some(Cycle {
+            start: 2,
+            length: 4,
+        });
+
+        assert_eq!(find_cycle(0, rho), expected);
+        assert_eq!(floyd(0, rho), expected);
+        assert_eq!(brent(0, rho), expected);
+
+        assert_eq!(
+            find_cycle(3, rho),
+            Some(Cycle {
+                start: 0,
+                length: 4
+            })
+        );
+    }
+
+    #[test]
+    fn fixed_point() {
+        let expected = Some(Cycle {
+            start: 0,
+            length: 1,
+        });
+
+        assert_eq!(find_cycle(7, |&state| Some(state)), expected);
+        assert_eq!(floyd(7, |&state| Some(state)), expected);
+        assert_eq!(brent(7, |&state| Some(state)), expected);
+    }
+
+    #[test]
+    fn ends() {
+        let countdown = |&state: &u8| state.checked_sub(1);
+
+        assert_eq!(find_cycle(10, countdown), None);
+        assert_eq!(floyd(10, countdown), None);
+        assert_eq!(brent(10, countdown), None);
+    }
+
+    #[test]
+    fn reduce() {
+        let cycle = Cycle {
+            start: 2,
+            length: 4,
+        };
+
+        assert_eq!(cycle.reduce(1), 1);
+        assert_eq!(cycle.reduce(5), 5);
+        assert_eq!(cycle.reduce(6), 2);
+        assert_eq!(cycle.reduce(1_000_000_001), 5);
+    }
+
+    proptest! {
+        #[test]
+        fn algorithms_agree(
+            successors in prop::collection::vec(0..64usize, 64),
+            initial in 0..64usize,
+        ) {
+            let step = |&state: &usize| Some(successors[state]);
+            let cycle = find_cycle(initial, step).unwrap();
+
+            prop_assert_eq!(floyd(initial, step), Some(cycle));
+            prop_assert_eq!(brent(initial, step), Some(cycle));
+
+            let states = std::iter::successors(Some(initial), step)
+                .take(cycle.start + cycle.length + 1)
+                .collect::<Vec<_>>();
+
+            prop_assert_eq!(states[cycle.start], states[cycle.start + cycle.length]);
+
+            let distinct = states[..cycle.start + cycle.length]
+                .iter()
+                .collect::<HashSet<_>>();
+            prop_assert_eq!(distinct.len(), cycle.start + cycle.length);
+        }
+    }
+}
@@ -0,0 +1,173 @@
+use std::{error::Error, fmt::Display};
+
+use crate::{parse::ParseError, GridError, NoSolution, Part};
+
+pub type PuzzleResult<T> = Result<T, PuzzleError>;
+
+/// Why a solution couldn't produce an answer.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum PuzzleError {
+    /// The input isn't in the expected format; `line` and `column` count from 1.
+    Parse {
+        line: usize,
+        column: usize,
+        message: String,
+    },
+    /// The input is well formed but has no answer.
+    NoSolution(String),
+    /// Something the puzzle promises turned out not to hold.
+    Invariant(String),
+}
+
+impl PuzzleError {
+    pub fn parse(line: usize, column: usize, message: impl Into<String>) -> Self {
+        Self::Parse {
+            line,
+            column,
+            message: message.into(),
+        }
+    }
+
+    pub fn no_solution(message: impl Into<String>) -> Self {
+        Self::NoSolution(message.into())
+    }
+
+    pub fn invariant(message: impl Into<String>) -> Self {
+        Self::Invariant(message.into())
+    }
+}
+
+impl Display for PuzzleError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Parse {
+                line,
+                column,
+                message,
+            } => write!(
+                f,
+                "Invalid input at line {}, column {}: {}",
+                line, column, message
+            ),
+            Self::NoSolution(message) => write!(f, "No solution: {}", message),
+            Self::Invariant(message) => write!(f, "Invariant violated: {}", message),
+        }
+    }
+}
+
+impl Error for PuzzleError {}
+
+impl From<ParseError> for PuzzleError {
+    fn from(value: ParseError) -> Self {
+        Self::parse(
+            value.line,
+            value.column,
+            format!("expected {}, found {}", value.expected, value.found),
+        )
+    }
+}
+
+impl From<GridError> for PuzzleError {
+    fn from(value: GridError) -> Self {
+        match value {
+            GridError::Ragged {
+                row,
+                expected,
+                found,
+            } => Self::parse(
+                row + 1,
+                found.min(expected) + 1,
+                format!("row is {} wide, expected {}", found, expected),
+            ),
+            GridError::InvalidCell { x, y, character } => {
+                Self::parse(y + 1, x + 1, format!("unexpected '{}'", character))
+            }
+        }
+    }
+}
+
+impl From<NoSolution> for PuzzleError {
+    fn from(value: NoSolution) -> Self {
+        Self::NoSolution(value.reason().to_string())
+    }
+}
+
+/// A [`PuzzleError`] along with which puzzle, and which part of it, failed.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SolveError {
+    pub year: u16,
+    pub day: u8,
+    /// `None` when parsing failed.
+    pub part: Option<Part>,
+    pub error: PuzzleError,
+}
+
+impl Display for SolveError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{} Day {} ", self.year, self.day)?;
+
+        match self.part {
+            Some(part) => write!(f, "Part {}", part)?,
+            None => write!(f, "parse")?,
+        }
+
+        write!(f, ": {}", self.error)
+    }
+}
+
+impl Error for SolveError {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn conversions() {
+        let error = PuzzleError::from(GridError::InvalidCell {
+            x: 2,
+            y: 0,
+            character: '?',
+        });
+
+        assert_eq!(
+            error.to_string(),
+            "Invalid input at line 1, column 3: unexpected '?'"
+        );
+        assert_eq!(
+            PuzzleError::from(NoSolution::Negative).to_string(),
+            "No solution: every solution is negative"
+        );
+        assert_eq!(
+            PuzzleError::from(ParseError {
+                line: 4,
+                column: 7,
+                expected: "a digit".to_string(),
+                found: "'x'".to_string(),
+            }),
+            PuzzleError::parse(4, 7, "expected a digit, found 'x'")
+        );
+    }
+
+    #[test]
+    fn located() {
+        let error = SolveError {
+            year: 2024,
+            day: 13,
+            part: Some(Part::Two),
+            error: PuzzleError::invariant("robot left the map"),
+        };
+
+        assert_eq!(
+            error.to_string(),
+            "2024 Day 13 Part Two: Invariant violated: robot left the map"
+        );
+        assert_eq!(
+            SolveError {
+                part: None,
+                ..error
+            }
+            .to_string(),
+            "2024 Day 13 parse: Invariant violated: robot left the map"
+        );
+    }
+}
@@ -0,0 +1,358 @@
+use std::{
+    collections::{BTreeMap, HashSet},
+    env,
+    error::Error,
+    fmt::Display,
+    fs, io,
+    path::{Path, PathBuf},
+};
+
+use crate::{
+    answers::{answer_from_value, part_from_key},
+    solution::solve_part,
+    Part, Solution,
+};
+
+#[derive(Debug)]
+pub enum ExampleError {
+    Read { path: PathBuf, source: io::Error },
+    Parse { path: PathBuf, message: String },
+}
+
+impl Display for ExampleError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Read { path, source } => {
+                write!(f, "Failed to read example {}: {}", path.display(), source)
+            }
+            Self::Parse { path, message } => {
+                write!(f, "Invalid example {}: {}", path.display(), message)
+            }
+        }
+    }
+}
+
+impl Error for ExampleError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match self {
+            Self::Read { source, .. } => Some(source),
+            Self::Parse { .. } => None,
+        }
+    }
+}
+
+/// A puzzle example, read from a file in a day's `examples` directory:
+///
+/// ```toml
+/// one = 11
+/// two = 31
+/// input = '''
+/// 3   4
+/// 4   3
+/// '''
+/// ```
+///
+/// Either answer may be left out, as some examples only apply to one part.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Example {
+    pub name: String,
+    pub input: String,
+    pub answers: BTreeMap<Part, String>,
+}
+
+impl Example {
+    pub fn load(path: &Path) -> Result<Self, ExampleError> {
+        let raw = fs::read_to_string(path).map_err(|source| ExampleError::Read {
+            path: path.to_path_buf(),
+            source,
+        })?;
+
+        let name = path
+            .file_stem()
+            .unwrap_or_default()
+            .to_string_lossy()
+            .into_owned();
+
+        Self::parse(name, &raw).map_err(|message| ExampleError::Parse {
+            path: path.to_path_buf(),
+            message,
+        })
+    }
+
+    fn parse(name: String, raw: &str) -> Result<Self, String> {
+        let table = raw
+            .parse::<toml::Table>()
+            .map_err(|error| error.to_string())?;
+        let mut input = None;
+        let mut answers = BTreeMap::new();
+
+        for (key, value) in table {
+            if key == "input" {
+                let value = value.as_str().ok_or("Input must be a string")?;
+                input = Some(value.to_string());
+                continue;
+            }
+
+            let part = part_from_key(&key).ok_or_else(|| format!("Unknown key: {}", key))?;
+            let answer = answer_from_value(&value)
+                .ok_or_else(|| format!("Unsupported answer for part {}: {}", part, value))?;
+
+            answers.insert(part, answer);
+        }
+
+        if answers.is_empty() {
+            return Err("Expected an answer for part one or two".to_string());
+        }
+
+        Ok(Self {
+            name,
+            input: input.ok_or("Missing input")?,
+            answers,
+        })
+    }
+
+    /// The path of every example in `directory`, sorted by name.
+    pub fn paths(directory: &Path) -> Result<Vec<PathBuf>, ExampleError> {
+        let read_error = |source| ExampleError::Read {
+            path: directory.to_path_buf(),
+            source,
+        };
+
+        let mut paths = fs::read_dir(directory)
+            .map_err(read_error)?
+            .map(|entry| entry.map(|entry| entry.path()))
+            .collect::<Result<Vec<_>, _>>()
+            .map_err(read_error)?;
+
+        paths.retain(|path| {
+            path.extension()
+                .is_some_and(|extension| extension == "toml")
+        });
+        paths.sort();
+
+        Ok(paths)
+    }
+
+    /// Describes every way `S` disagrees with this example.
+    pub fn check<S: Solution>(&self) -> Vec<String> {
+        let input = match S::parse(&self.input) {
+            Ok(input) => input,
+            Err(error) => return vec![format!("{}: {}", self.name, error)],
+        };
+
+        self.answers
+            .iter()
+            .filter_map(|(&part, expected)| match solve_part::<S>(&input, part) {
+                Ok(answer) if answer == *expected => None,
+                Ok(answer) => Some(format!(
+                    "{} Part {}: expected {}, got {}",
+                    self.name, part, expected, answer
+                )),
+                Err(error) => Some(format!("{} Part {}: {}", self.name, part, error)),
+            })
+            .collect()
+    }
+}
+
+/// Runs `S` against the example at `path`, panicking with every mismatch.
+pub fn check_example<S: Solution>(path: impl AsRef<Path>) {
+    let example = Example::load(path.as_ref()).unwrap_or_else(|error| panic!("{}", error));
+    let failures = example.check::<S>();
+
+    assert!(failures.is_empty(), "{}", failures.join("\n"));
+}
+
+/// For a day's build script: writes a test per file in the crate's `examples` directory for
+/// [`example_tests!`] to include, so each example passes or fails under its own name.
+pub fn write_example_tests() {
+    let manifest = env::var("CARGO_MANIFEST_DIR").expect("CARGO_MANIFEST_DIR is set by Cargo");
+    let out = env::var("OUT_DIR").expect("OUT_DIR is set for build scripts");
+    let directory = Path::new(&manifest).join("examples");
+
+    // Cargo watches the whole directory, so adding an example regenerates the tests.
+    println!("cargo:rerun-if-changed={}", directory.display());
+
+    fs::write(
+        Path::new(&out).join("examples.rs"),
+        example_tests(&directory),
+    )
+    .expect("Failed to write example tests");
+}
+
+/// Problems become compile errors, which only surface when the tests are built.
+fn example_tests(directory: &Path) -> String {
+    let compile_error = |message: String| format!("compile_error!({:?});\n", message);
+
+    let paths = match Example::paths(directory) {
+        Ok(paths) if paths.is_empty() => {
+            return compile_error(format!("No examples in {}", directory.display()))
+        }
+        Ok(paths) => paths,
+        Err(error) => return compile_error(error.to_string()),
+    };
+
+    let mut names = HashSet::new();
+    let mut tests = String::new();
+
+    for path in paths {
+        let name = test_name(&path);
+
+        if !names.insert(name.clone()) {
+            return compile_error(format!(
+                "{} has the same test name as another example: {}",
+                path.display(),
+                name
+            ));
+        }
+
+        tests.push_str(&format!(
+            "#[test]\nfn {}() {{\n    check({:?});\n}}\n",
+            name,
+            path.display().to_string()
+        ));
+    }
+
+    tests
+}
+
+/// A file's stem as a function name, such as `report_1` for `report-1.toml`.
+fn test_name(path: &Path) -> String {
+    let name = path
+        .file_stem()
+        .unwrap_or_default()
+        .to_string_lossy()
+        .chars()
+        .map(|character| match character {
+            'a'..='z' | '0'..='9' => character,
+            'A'..='Z' => character.to_ascii_lowercase(),
+            _ => '_',
+        })
+        .collect::<String>();
+
+    if name.starts_with(|character: char| character.is_ascii_alphabetic()) {
+        name
+    } else {
+        format!("example_{}", name)
+    }
+}
+
+/// Adds a test per file in the crate's `examples` directory. The crate's build script lists
+/// them by calling [`write_example_tests`].
+#[macro_export]
+macro_rules! example_tests {
+    ($solution: ident) => {
+        #[cfg(test)]
+        mod examples {
+            fn check(path: &str) {
+                $crate::check_example::<super::$solution>(path);
+            }
+
+            include!(concat!(env!("OUT_DIR"), "/examples.rs"));
+        }
+    };
+}
+
+#[cfg(test)]
+mod tests {
+    use std::fmt::Display;
+
+    use super::*;
+    use crate::{PuzzleError, PuzzleResult};
+
+    struct Count;
+
+    impl Solution for Count {
+        const YEAR: u16 = 2000;
+        const DAY: u8 = 1;
+
+        type Input = Vec<String>;
+
+        fn parse(raw: &str) -> PuzzleResult<Self::Input> {
+            if raw.is_empty() {
+                return Err(PuzzleError::parse(1, 1, "empty"));
+            }
+
+            Ok(raw.lines().map(str::to_string).collect())
+        }
+
+        fn part_one(input: &Self::Input) -> PuzzleResult<impl Display> {
+            Ok(input.len())
+        }
+
+        fn part_two(input: &Self::Input) -> PuzzleResult<impl Display> {
+            Ok(input.concat())
+        }
+    }
+
+    fn example(raw: &str) -> Example {
+        Example::parse("test".to_string(), raw).unwrap()
+    }
+
+    #[test]
+    fn parse() {
+        let example = example("one = 2\ntwo = \"ab\"\ninput = '''\na\nb\n'''");
+
+        assert_eq!(example.input, "a\nb\n");
+        assert_eq!(example.answers[&Part::One], "2");
+        assert_eq!(example.answers[&Part::Two], "ab");
+        assert!(example.check::<Count>().is_empty());
+    }
+
+    #[test]
+    fn parse_invalid() {
+        assert!(Example::parse(String::new(), "one = 1").is_err());
+        assert!(Example::parse(String::new(), "input = 'a'").is_err());
+        assert!(Example::parse(String::new(), "three = 1\ninput = 'a'").is_err());
+        assert!(Example::parse(String::new(), "one = 1.5\ninput = 'a'").is_err());
+    }
+
+    #[test]
+    fn mismatches() {
+        assert_eq!(
+            example("one = 3\ninput = 'a'").check::<Count>(),
+            ["test Part One: expected 3, got 1"]
+        );
+        assert_eq!(
+            example("two = 'a'\ninput = ''").check::<Count>(),
+            ["test: Invalid input at line 1, column 1: empty"]
+        );
+    }
+
+    #[test]
+    fn test_names() {
+        assert_eq!(test_name(Path::new("examples/report-1.toml")), "report_1");
+        assert_eq!(test_name(Path::new("Part Two.toml")), "part_two");
+        assert_eq!(test_name(Path::new("2.toml")), "example_2");
+        assert_eq!(test_name(Path::new("_.toml")), "example__");
+    }
+
+    #[test]
+    fn generated_tests() {
+        let directory = env::temp_dir().join(format!("example-tests-{}", std::process::id()));
+        fs::create_dir_all(&directory).unwrap();
+
+        assert!(example_tests(&directory).starts_with("compile_error!(\"No examples in"));
+
+        for name in ["b.toml", "a-1.toml", "notes.txt"] {
+            fs::write(directory.join(name), "").unwrap();
+        }
+
+        let tests = example_tests(&directory);
+        let a = directory.join("a-1.toml");
+
+        assert_eq!(
+            tests,
+            format!(
+                "#[test]\nfn a_1() {{\n    check({:?});\n}}\n\
+                 #[test]\nfn b() {{\n    check({:?});\n}}\n",
+                a.display().to_string(),
+                directory.join("b.toml").display().to_string()
+            )
+        );
+
+        fs::write(directory.join("a_1.toml"), "").unwrap();
+        assert!(example_tests(&directory).contains("same test name"));
+
+        fs::remove_dir_all(&directory).unwrap();
+    }
+}
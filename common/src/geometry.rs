@@ -0,0 +1,411 @@
+use std::{
+    error::Error,
+    fmt::Display,
+    ops::{Add, Sub},
+    str::FromStr,
+};
+
+use num_traits::{PrimInt, WrappingAdd, WrappingSub};
+
+/// Anything that moves a point by one unit offset.
+pub trait Heading: Copy {
+    /// `(x, y)` with `y` growing downwards, like rows in a grid.
+    fn offset(self) -> (i8, i8);
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub enum Direction4 {
+    Up,
+    Right,
+    Down,
+    Left,
+}
+
+impl Direction4 {
+    /// Clockwise from up.
+    pub const ALL: [Self; 4] = [Self::Up, Self::Right, Self::Down, Self::Left];
+
+    /// Accepts arrows (`^v<>`), compass points (`NSEW`) and `UDLR`, in either case.
+    pub fn from_char(value: char) -> Option<Self> {
+        Self::from_arrow(value).or_else(|| match value.to_ascii_uppercase() {
+            'U' | 'N' => Some(Self::Up),
+            'D' | 'S' => Some(Self::Down),
+            'L' | 'W' => Some(Self::Left),
+            'R' | 'E' => Some(Self::Right),
+            _ => None,
+        })
+    }
+
+    /// Accepts only the arrows `^v<>`.
+    pub fn from_arrow(value: char) -> Option<Self> {
+        match value {
+            '^' => Some(Self::Up),
+            'v' => Some(Self::Down),
+            '<' => Some(Self::Left),
+            '>' => Some(Self::Right),
+            _ => None,
+        }
+    }
+
+    pub fn to_char(self) -> char {
+        match self {
+            Self::Up => '^',
+            Self::Right => '>',
+            Self::Down => 'v',
+            Self::Left => '<',
+        }
+    }
+
+    pub fn rotate_right(self) -> Self {
+        match self {
+            Self::Up => Self::Right,
+            Self::Right => Self::Down,
+            Self::Down => Self::Left,
+            Self::Left => Self::Up,
+        }
+    }
+
+    pub fn rotate_left(self) -> Self {
+        self.opposite().rotate_right()
+    }
+
+    pub fn opposite(self) -> Self {
+        self.rotate_right().rotate_right()
+    }
+
+    pub fn is_horizontal(self) -> bool {
+        matches!(self, Self::Left | Self::Right)
+    }
+
+    pub fn is_vertical(self) -> bool {
+        !self.is_horizontal()
+    }
+}
+
+impl Heading for Direction4 {
+    fn offset(self) -> (i8, i8) {
+        match self {
+            Self::Up => (0, -1),
+            Self::Right => (1, 0),
+            Self::Down => (0, 1),
+            Self::Left => (-1, 0),
+        }
+    }
+}
+
+impl TryFrom<char> for Direction4 {
+    type Error = InvalidDirection;
+
+    fn try_from(value: char) -> Result<Self, Self::Error> {
+        Self::from_char(value).ok_or_else(|| InvalidDirection(value.to_string()))
+    }
+}
+
+impl Display for Direction4 {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.to_char())
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub enum Direction8 {
+    Up,
+    UpRight,
+    Right,
+    DownRight,
+    Down,
+    DownLeft,
+    Left,
+    UpLeft,
+}
+
+impl Direction8 {
+    /// Clockwise from up.
+    pub const ALL: [Self; 8] = [
+        Self::Up,
+        Self::UpRight,
+        Self::Right,
+        Self::DownRight,
+        Self::Down,
+        Self::DownLeft,
+        Self::Left,
+        Self::UpLeft,
+    ];
+
+    fn index(self) -> usize {
+        Self::ALL
+            .iter()
+            .position(|&direction| direction == self)
+            .unwrap()
+    }
+
+    /// Rotates clockwise by 45 degrees.
+    pub fn rotate_right(self) -> Self {
+        Self::ALL[(self.index() + 1) % 8]
+    }
+
+    /// Rotates anticlockwise by 45 degrees.
+    pub fn rotate_left(self) -> Self {
+        Self::ALL[(self.index() + 7) % 8]
+    }
+
+    pub fn opposite(self) -> Self {
+        Self::ALL[(self.index() + 4) % 8]
+    }
+
+    pub fn is_diagonal(self) -> bool {
+        !self.index().is_multiple_of(2)
+    }
+}
+
+impl Heading for Direction8 {
+    fn offset(self) -> (i8, i8) {
+        match self {
+            Self::Up => (0, -1),
+            Self::UpRight => (1, -1),
+            Self::Right => (1, 0),
+            Self::DownRight => (1, 1),
+            Self::Down => (0, 1),
+            Self::DownLeft => (-1, 1),
+            Self::Left => (-1, 0),
+            Self::UpLeft => (-1, -1),
+        }
+    }
+}
+
+impl From<Direction4> for Direction8 {
+    fn from(value: Direction4) -> Self {
+        match value {
+            Direction4::Up => Self::Up,
+            Direction4::Right => Self::Right,
+            Direction4::Down => Self::Down,
+            Direction4::Left => Self::Left,
+        }
+    }
+}
+
+/// Parses compass points such as `N` or `SW`, and anything [`Direction4::from_char`] accepts.
+impl FromStr for Direction8 {
+    type Err = InvalidDirection;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut chars = s.chars();
+
+        match (chars.next(), chars.next(), chars.next()) {
+            (Some(value), None, None) => Direction4::try_from(value).map(Self::from),
+            (Some(first), Some(second), None) => {
+                match (first.to_ascii_uppercase(), second.to_ascii_uppercase()) {
+                    ('N', 'E') => Ok(Self::UpRight),
+                    ('S', 'E') => Ok(Self::DownRight),
+                    ('S', 'W') => Ok(Self::DownLeft),
+                    ('N', 'W') => Ok(Self::UpLeft),
+                    _ => Err(InvalidDirection(s.to_string())),
+                }
+            }
+            _ => Err(InvalidDirection(s.to_string())),
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct InvalidDirection(pub String);
+
+impl Display for InvalidDirection {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "'{}' is not a direction", self.0)
+    }
+}
+
+impl Error for InvalidDirection {}
+
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct Point<T> {
+    pub x: T,
+    pub y: T,
+}
+
+impl<T> Point<T> {
+    pub const fn new(x: T, y: T) -> Self {
+        Self { x, y }
+    }
+}
+
+fn checked_offset<T: PrimInt>(value: T, offset: i8) -> Option<T> {
+    let magnitude = T::from(offset.unsigned_abs())?;
+
+    if offset < 0 {
+        value.checked_sub(&magnitude)
+    } else {
+        value.checked_add(&magnitude)
+    }
+}
+
+fn wrapping_offset<T: PrimInt + WrappingAdd + WrappingSub>(value: T, offset: i8) -> T {
+    let magnitude = T::from(offset.unsigned_abs()).expect("Offset fits every integer");
+
+    if offset < 0 {
+        value.wrapping_sub(&magnitude)
+    } else {
+        value.wrapping_add(&magnitude)
+    }
+}
+
+impl<T: PrimInt> Point<T> {
+    /// `None` if either coordinate would overflow.
+    pub fn checked_step(self, heading: impl Heading) -> Option<Self> {
+        let (x, y) = heading.offset();
+
+        Some(Self::new(
+            checked_offset(self.x, x)?,
+            checked_offset(self.y, y)?,
+        ))
+    }
+
+    /// `None` unless the new point is within `0..width` and `0..height`.
+    pub fn bounded_step(self, heading: impl Heading, width: T, height: T) -> Option<Self> {
+        self.checked_step(heading)
+            .filter(|point| point.x >= T::zero() && point.y >= T::zero())
+            .filter(|point| point.x < width && point.y < height)
+    }
+
+    pub fn wrapping_step(self, heading: impl Heading) -> Self
+    where
+        T: WrappingAdd + WrappingSub,
+    {
+        let (x, y) = heading.offset();
+
+        Self::new(wrapping_offset(self.x, x), wrapping_offset(self.y, y))
+    }
+
+    /// The points up, right, down and left of this one that don't overflow.
+    pub fn neighbours4(self) -> impl Iterator<Item = Self> {
+        Direction4::ALL
+            .into_iter()
+            .filter_map(move |direction| self.checked_step(direction))
+    }
+
+    /// Like [`Point::neighbours4`], including diagonals.
+    pub fn neighbours8(self) -> impl Iterator<Item = Self> {
+        Direction8::ALL
+            .into_iter()
+            .filter_map(move |direction| self.checked_step(direction))
+    }
+
+    pub fn manhattan_distance(self, other: Self) -> T {
+        let distance = |a: T, b: T| if a > b { a - b } else { b - a };
+
+        distance(self.x, other.x) + distance(self.y, other.y)
+    }
+}
+
+impl<T: Add<Output = T>> Add for Point<T> {
+    type Output = Self;
+
+    fn add(self, rhs: Self) -> Self::Output {
+        Self::new(self.x + rhs.x, self.y + rhs.y)
+    }
+}
+
+impl<T: Sub<Output = T>> Sub for Point<T> {
+    type Output = Self;
+
+    fn sub(self, rhs: Self) -> Self::Output {
+        Self::new(self.x - rhs.x, self.y - rhs.y)
+    }
+}
+
+impl<T> From<(T, T)> for Point<T> {
+    fn from((x, y): (T, T)) -> Self {
+        Self::new(x, y)
+    }
+}
+
+impl<T> From<Point<T>> for (T, T) {
+    fn from(value: Point<T>) -> Self {
+        (value.x, value.y)
+    }
+}
+
+impl<T: Display> Display for Point<T> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "({}, {})", self.x, self.y)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parse_direction() {
+        for (characters, direction) in [
+            ("^UuNn", Direction4::Up),
+            ("vDdSs", Direction4::Down),
+            ("<LlWw", Direction4::Left),
+            (">RrEe", Direction4::Right),
+        ] {
+            for character in characters.chars() {
+                assert_eq!(Direction4::from_char(character), Some(direction));
+            }
+        }
+
+        assert_eq!(Direction4::from_char('x'), None);
+        assert_eq!(Direction4::from_arrow('<'), Some(Direction4::Left));
+        assert_eq!(Direction4::from_arrow('U'), None);
+        assert_eq!("NW".parse(), Ok(Direction8::UpLeft));
+        assert_eq!("s".parse(), Ok(Direction8::Down));
+        assert!("NS".parse::<Direction8>().is_err());
+    }
+
+    #[test]
+    fn rotate() {
+        assert_eq!(Direction4::Up.rotate_right(), Direction4::Right);
+        assert_eq!(Direction4::Up.rotate_left(), Direction4::Left);
+        assert_eq!(Direction4::Left.opposite(), Direction4::Right);
+        assert_eq!(Direction8::Up.rotate_right(), Direction8::UpRight);
+        assert_eq!(Direction8::Up.rotate_left(), Direction8::UpLeft);
+        assert_eq!(Direction8::DownLeft.opposite(), Direction8::UpRight);
+
+        for direction in Direction4::ALL {
+            assert_eq!(direction.rotate_right().rotate_left(), direction);
+            assert_eq!(
+                Direction8::from(direction.opposite()),
+                Direction8::from(direction).opposite()
+            );
+        }
+    }
+
+    #[test]
+    fn step() {
+        let origin = Point::new(0u8, 0);
+
+        assert_eq!(origin.checked_step(Direction4::Up), None);
+        assert_eq!(
+            origin.checked_step(Direction8::DownRight),
+            Some(Point::new(1, 1))
+        );
+        assert_eq!(
+            origin.wrapping_step(Direction4::Left),
+            Point::new(u8::MAX, 0)
+        );
+        assert_eq!(
+            Point::new(1u8, 0).bounded_step(Direction4::Right, 2, 1),
+            None
+        );
+        assert_eq!(
+            Point::new(0i8, 0).bounded_step(Direction4::Left, 2, 2),
+            None
+        );
+        assert_eq!(
+            Point::new(-1i32, 0).checked_step(Direction4::Left),
+            Some(Point::new(-2, 0))
+        );
+    }
+
+    #[test]
+    fn neighbours() {
+        assert_eq!(Point::new(0u8, 0).neighbours4().count(), 2);
+        assert_eq!(Point::new(1u8, 1).neighbours8().count(), 8);
+        assert_eq!(Point::new(3u32, 7).manhattan_distance(Point::new(5, 2)), 7);
+    }
+}
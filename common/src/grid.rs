@@ -0,0 +1,331 @@
+use std::{
+    error::Error,
+    fmt::Display,
+    ops::{Index, IndexMut},
+};
+
+use crate::{width_height_2d_iter, Direction4, Direction8, Heading, Point};
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum GridError {
+    Ragged {
+        row: usize,
+        expected: usize,
+        found: usize,
+    },
+    InvalidCell {
+        x: usize,
+        y: usize,
+        character: char,
+    },
+}
+
+impl Display for GridError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Ragged {
+                row,
+                expected,
+                found,
+            } => write!(
+                f,
+                "Row {} is {} wide, expected {} like the first row",
+                row, found, expected
+            ),
+            Self::InvalidCell { x, y, character } => {
+                write!(f, "Unexpected '{}' at ({}, {})", character, x, y)
+            }
+        }
+    }
+}
+
+impl Error for GridError {}
+
+/// A rectangular map stored row by row, indexed by `(x, y)` from the top left.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Grid<T> {
+    cells: Vec<T>,
+    width: usize,
+    height: usize,
+}
+
+impl<T> Grid<T> {
+    pub fn new(width: usize, height: usize, cells: Vec<T>) -> Self {
+        assert_eq!(
+            cells.len(),
+            width * height,
+            "A {}x{} grid needs {} cells",
+            width,
+            height,
+            width * height
+        );
+
+        Self {
+            cells,
+            width,
+            height,
+        }
+    }
+
+    pub fn filled(width: usize, height: usize, value: T) -> Self
+    where
+        T: Clone,
+    {
+        Self::new(width, height, vec![value; width * height])
+    }
+
+    /// Parses one row per line, converting each character with `f`.
+    pub fn from_str_with(raw: &str, mut f: impl FnMut(char) -> T) -> Result<Self, GridError> {
+        Self::try_from_str_with(raw, |character| Some(f(character)))
+    }
+
+    /// Like [`Grid::from_str_with`], rejecting characters `f` returns `None` for.
+    pub fn try_from_str_with(
+        raw: &str,
+        mut f: impl FnMut(char) -> Option<T>,
+    ) -> Result<Self, GridError> {
+        let mut cells = Vec::with_capacity(raw.len());
+        let mut width = None;
+        let mut height = 0;
+
+        for (y, line) in raw.lines().enumerate() {
+            let start = cells.len();
+
+            for (x, character) in line.chars().enumerate() {
+                let cell = f(character).ok_or(GridError::InvalidCell { x, y, character })?;
+                cells.push(cell);
+            }
+
+            let found = cells.len() - start;
+            let expected = *width.get_or_insert(found);
+
+            if found != expected {
+                return Err(GridError::Ragged {
+                    row: y,
+                    expected,
+                    found,
+                });
+            }
+
+            height += 1;
+        }
+
+        Ok(Self::new(width.unwrap_or_default(), height, cells))
+    }
+
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    pub fn height(&self) -> usize {
+        self.height
+    }
+
+    pub fn contains(&self, position: impl Into<(usize, usize)>) -> bool {
+        let (x, y) = position.into();
+        x < self.width && y < self.height
+    }
+
+    fn offset(&self, position: impl Into<(usize, usize)>) -> Option<usize> {
+        let (x, y) = position.into();
+        self.contains((x, y)).then(|| y * self.width + x)
+    }
+
+    pub fn get(&self, position: impl Into<(usize, usize)>) -> Option<&T> {
+        self.offset(position).map(|offset| &self.cells[offset])
+    }
+
+    pub fn get_mut(&mut self, position: impl Into<(usize, usize)>) -> Option<&mut T> {
+        self.offset(position).map(|offset| &mut self.cells[offset])
+    }
+
+    /// Every position, row by row.
+    pub fn positions(&self) -> impl Iterator<Item = (usize, usize)> {
+        width_height_2d_iter(self.width, self.height)
+    }
+
+    /// Every cell alongside its position, row by row.
+    pub fn iter(&self) -> impl Iterator<Item = ((usize, usize), &T)> {
+        self.positions().zip(&self.cells)
+    }
+
+    pub fn position(&self, mut predicate: impl FnMut(&T) -> bool) -> Option<(usize, usize)> {
+        self.iter()
+            .find(|(_, cell)| predicate(cell))
+            .map(|(position, _)| position)
+    }
+
+    pub fn row(&self, y: usize) -> &[T] {
+        &self.cells[y * self.width..(y + 1) * self.width]
+    }
+
+    pub fn rows(&self) -> impl Iterator<Item = &[T]> {
+        self.cells.chunks(self.width.max(1))
+    }
+
+    pub fn column(&self, x: usize) -> impl Iterator<Item = &T> {
+        assert!(x < self.width, "Column {} is out of bounds", x);
+        self.cells.iter().skip(x).step_by(self.width)
+    }
+
+    pub fn columns(&self) -> impl Iterator<Item = impl Iterator<Item = &T>> {
+        (0..self.width).map(|x| self.column(x))
+    }
+
+    /// Positions up, right, down and left of `position` that are inside the grid.
+    pub fn neighbours4(&self, position: (usize, usize)) -> impl Iterator<Item = (usize, usize)> {
+        self.steps(position, Direction4::ALL)
+    }
+
+    /// Like [`Grid::neighbours4`], including diagonals.
+    pub fn neighbours8(&self, position: (usize, usize)) -> impl Iterator<Item = (usize, usize)> {
+        self.steps(position, Direction8::ALL)
+    }
+
+    fn steps<H: Heading>(
+        &self,
+        position: (usize, usize),
+        headings: impl IntoIterator<Item = H>,
+    ) -> impl Iterator<Item = (usize, usize)> {
+        let (width, height) = (self.width, self.height);
+        let position = Point::from(position);
+
+        headings.into_iter().filter_map(move |heading| {
+            position
+                .bounded_step(heading, width, height)
+                .map(Into::into)
+        })
+    }
+
+    pub fn map<U>(&self, f: impl FnMut(&T) -> U) -> Grid<U> {
+        Grid::new(self.width, self.height, self.cells.iter().map(f).collect())
+    }
+}
+
+impl<T, P: Into<(usize, usize)>> Index<P> for Grid<T> {
+    type Output = T;
+
+    fn index(&self, position: P) -> &Self::Output {
+        let position = position.into();
+
+        self.get(position).unwrap_or_else(|| {
+            panic!(
+                "{:?} is outside the {}x{} grid",
+                position, self.width, self.height
+            )
+        })
+    }
+}
+
+impl<T, P: Into<(usize, usize)>> IndexMut<P> for Grid<T> {
+    fn index_mut(&mut self, position: P) -> &mut Self::Output {
+        let position = position.into();
+        let (width, height) = (self.width, self.height);
+
+        self.get_mut(position)
+            .unwrap_or_else(|| panic!("{:?} is outside the {}x{} grid", position, width, height))
+    }
+}
+
+impl<T: Display> Display for Grid<T> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        for (y, row) in self.rows().enumerate() {
+            if y > 0 {
+                writeln!(f)?;
+            }
+
+            for cell in row {
+                write!(f, "{}", cell)?;
+            }
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = "abc\ndef";
+
+    #[test]
+    fn parse() {
+        let grid = Grid::from_str_with(EXAMPLE, |character| character).unwrap();
+
+        assert_eq!((grid.width(), grid.height()), (3, 2));
+        assert_eq!(grid[(2, 0)], 'c');
+        assert_eq!(grid[(0, 1)], 'd');
+        assert_eq!(grid.get((3, 0)), None);
+        assert_eq!(grid[Point::new(1, 1)], 'e');
+        assert_eq!(grid.get((0, 2)), None);
+        assert_eq!(grid.to_string(), EXAMPLE);
+    }
+
+    #[test]
+    fn parse_errors() {
+        assert_eq!(
+            Grid::from_str_with("abc\nde", |character| character),
+            Err(GridError::Ragged {
+                row: 1,
+                expected: 3,
+                found: 2
+            })
+        );
+        assert_eq!(
+            Grid::try_from_str_with("12\n3x", |character| character.to_digit(10)),
+            Err(GridError::InvalidCell {
+                x: 1,
+                y: 1,
+                character: 'x'
+            })
+        );
+    }
+
+    #[test]
+    fn rows_and_columns() {
+        let grid = Grid::from_str_with(EXAMPLE, |character| character).unwrap();
+
+        assert_eq!(
+            grid.rows().collect::<Vec<_>>(),
+            [['a', 'b', 'c'], ['d', 'e', 'f']]
+        );
+        assert_eq!(grid.row(1), ['d', 'e', 'f']);
+        assert_eq!(
+            grid.columns()
+                .map(|column| column.collect::<String>())
+                .collect::<Vec<_>>(),
+            ["ad", "be", "cf"]
+        );
+    }
+
+    #[test]
+    fn iterate() {
+        let mut grid = Grid::filled(2, 2, 0);
+        grid[(1, 0)] = 5;
+        *grid.get_mut((0, 1)).unwrap() = 7;
+
+        assert_eq!(
+            grid.iter().collect::<Vec<_>>(),
+            [((0, 0), &0), ((1, 0), &5), ((0, 1), &7), ((1, 1), &0)]
+        );
+        assert_eq!(grid.position(|&cell| cell == 7), Some((0, 1)));
+        assert_eq!(grid.map(|cell| cell * 2)[(1, 0)], 10);
+    }
+
+    #[test]
+    fn neighbours() {
+        let grid = Grid::filled(3, 3, ());
+
+        assert_eq!(grid.neighbours4((1, 1)).count(), 4);
+        assert_eq!(
+            grid.neighbours4((0, 0)).collect::<Vec<_>>(),
+            [(1, 0), (0, 1)]
+        );
+        assert_eq!(grid.neighbours8((1, 1)).count(), 8);
+        assert_eq!(
+            grid.neighbours8((2, 2)).collect::<Vec<_>>(),
+            [(2, 1), (1, 2), (1, 1)]
+        );
+    }
+}
@@ -0,0 +1,531 @@
+//! Saves puzzle states as PNG or PPM images, and runs of them as animated GIFs.
+//!
+//! Like [`viz`](crate::viz), solutions call [`export`] or keep a [`Recording`] wherever there's
+//! something worth saving, and nothing is built or written until [`export_to`] picks a
+//! directory, which the runner does for `--export`.
+
+use std::{
+    collections::HashMap,
+    env,
+    error::Error,
+    fmt::Display,
+    fs::{self, File},
+    io::{self, BufWriter, Write},
+    path::{Path, PathBuf},
+    sync::Mutex,
+    time::Duration,
+};
+
+use num_traits::PrimInt;
+
+use crate::{
+    viz::{Colour, Frame},
+    Grid, Point,
+};
+
+/// Saves images from `solve_main` into `<dir>/<year>-day-<day>`.
+pub const EXPORT_DIR_VAR: &str = "AOC_EXPORT_DIR";
+
+/// The most colours a GIF frame can index without quantizing.
+const GIF_PALETTE: usize = 256;
+
+#[derive(Debug)]
+pub enum ImageError {
+    Io(io::Error),
+    Png(png::EncodingError),
+    Gif(gif::EncodingError),
+    TooLarge { width: usize, height: usize },
+    UnknownFormat(PathBuf),
+}
+
+impl Display for ImageError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Io(error) => write!(f, "Failed to write image: {}", error),
+            Self::Png(error) => write!(f, "Failed to encode PNG: {}", error),
+            Self::Gif(error) => write!(f, "Failed to encode GIF: {}", error),
+            Self::TooLarge { width, height } => {
+                write!(f, "A {}x{} image is too large to encode", width, height)
+            }
+            Self::UnknownFormat(path) => write!(
+                f,
+                "Unknown image format for {}, expected .png or .ppm",
+                path.display()
+            ),
+        }
+    }
+}
+
+impl Error for ImageError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match self {
+            Self::Io(error) => Some(error),
+            Self::Png(error) => Some(error),
+            Self::Gif(error) => Some(error),
+            Self::TooLarge { .. } | Self::UnknownFormat(_) => None,
+        }
+    }
+}
+
+impl From<io::Error> for ImageError {
+    fn from(value: io::Error) -> Self {
+        Self::Io(value)
+    }
+}
+
+impl From<png::EncodingError> for ImageError {
+    fn from(value: png::EncodingError) -> Self {
+        Self::Png(value)
+    }
+}
+
+impl From<gif::EncodingError> for ImageError {
+    fn from(value: gif::EncodingError) -> Self {
+        Self::Gif(value)
+    }
+}
+
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+pub struct Rgb(pub u8, pub u8, pub u8);
+
+impl Rgb {
+    pub const BLACK: Self = Self(0, 0, 0);
+    pub const WHITE: Self = Self(255, 255, 255);
+}
+
+impl From<Colour> for Rgb {
+    /// Roughly how a terminal shows each colour.
+    fn from(value: Colour) -> Self {
+        match value {
+            Colour::Red => Self(205, 49, 49),
+            Colour::Green => Self(13, 188, 121),
+            Colour::Yellow => Self(229, 229, 16),
+            Colour::Blue => Self(36, 114, 200),
+            Colour::Magenta => Self(188, 63, 188),
+            Colour::Cyan => Self(17, 168, 205),
+            Colour::White => Self::WHITE,
+            Colour::Grey => Self(102, 102, 102),
+        }
+    }
+}
+
+/// One pixel per cell, upscaled with [`Image::scaled`] to be big enough to see.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Image {
+    pixels: Grid<Rgb>,
+}
+
+impl Image {
+    pub fn new(width: usize, height: usize, background: Rgb) -> Self {
+        Self {
+            pixels: Grid::filled(width, height, background),
+        }
+    }
+
+    pub fn from_grid<T>(grid: &Grid<T>, mut f: impl FnMut(&T) -> Rgb) -> Self {
+        let pixels = grid.iter().map(|(_, cell)| f(cell)).collect();
+
+        Self {
+            pixels: Grid::new(grid.width(), grid.height(), pixels),
+        }
+    }
+
+    /// Draws each cell in its colour; uncoloured cells are white, or black if they're blank.
+    pub fn from_frame(frame: &Frame) -> Self {
+        let mut image = Self::new(frame.width(), frame.height(), Rgb::BLACK);
+
+        for position in image.pixels.positions() {
+            let cell = frame.get(position).expect("Frame is the same size");
+            let pixel = match cell.colour {
+                Some(colour) => colour.into(),
+                None if cell.character == ' ' => Rgb::BLACK,
+                None => Rgb::WHITE,
+            };
+
+            image.set(position, pixel);
+        }
+
+        image
+    }
+
+    /// `points` in `foreground` on a black background, skipping any outside the image.
+    pub fn from_points<T: PrimInt>(
+        width: usize,
+        height: usize,
+        points: impl IntoIterator<Item = Point<T>>,
+        foreground: Rgb,
+    ) -> Self {
+        let mut image = Self::new(width, height, Rgb::BLACK);
+
+        for Point { x, y } in points {
+            if let (Some(x), Some(y)) = (x.to_usize(), y.to_usize()) {
+                image.set((x, y), foreground);
+            }
+        }
+
+        image
+    }
+
+    pub fn width(&self) -> usize {
+        self.pixels.width()
+    }
+
+    pub fn height(&self) -> usize {
+        self.pixels.height()
+    }
+
+    pub fn get(&self, position: impl Into<(usize, usize)>) -> Option<Rgb> {
+        self.pixels.get(position).copied()
+    }
+
+    /// Colours the pixel at `position`, ignoring positions outside the image.
+    pub fn set(&mut self, position: impl Into<(usize, usize)>, pixel: Rgb) {
+        if let Some(current) = self.pixels.get_mut(position) {
+            *current = pixel;
+        }
+    }
+
+    /// Every pixel becomes a `factor` by `factor` square.
+    pub fn scaled(&self, factor: usize) -> Self {
+        let mut image = Self::new(self.width() * factor, self.height() * factor, Rgb::BLACK);
+
+        for (x, y) in image.pixels.positions() {
+            image.set((x, y), self.pixels[(x / factor, y / factor)]);
+        }
+
+        image
+    }
+
+    fn bytes(&self) -> Vec<u8> {
+        self.pixels
+            .iter()
+            .flat_map(|(_, &Rgb(red, green, blue))| [red, green, blue])
+            .collect()
+    }
+
+    /// Binary PPM (`P6`), which almost anything can open.
+    pub fn write_ppm(&self, mut writer: impl Write) -> io::Result<()> {
+        write!(writer, "P6\n{} {}\n255\n", self.width(), self.height())?;
+        writer.write_all(&self.bytes())
+    }
+
+    pub fn write_png(&self, writer: impl Write) -> Result<(), ImageError> {
+        let width = self.width().try_into().map_err(|_| self.too_large())?;
+        let height = self.height().try_into().map_err(|_| self.too_large())?;
+        let mut encoder = png::Encoder::new(writer, width, height);
+        encoder.set_color(png::ColorType::Rgb);
+        encoder.set_depth(png::BitDepth::Eight);
+
+        encoder.write_header()?.write_image_data(&self.bytes())?;
+        Ok(())
+    }
+
+    /// Picks PNG or PPM from the extension of `path`.
+    pub fn save(&self, path: &Path) -> Result<(), ImageError> {
+        let extension = path.extension().and_then(|extension| extension.to_str());
+
+        match extension {
+            Some("png") => self.write_png(create(path)?),
+            Some("ppm") => {
+                let mut writer = create(path)?;
+                self.write_ppm(&mut writer)?;
+                Ok(writer.flush()?)
+            }
+            _ => Err(ImageError::UnknownFormat(path.to_path_buf())),
+        }
+    }
+
+    fn too_large(&self) -> ImageError {
+        ImageError::TooLarge {
+            width: self.width(),
+            height: self.height(),
+        }
+    }
+}
+
+fn create(path: &Path) -> Result<BufWriter<File>, ImageError> {
+    if let Some(parent) = path.parent() {
+        fs::create_dir_all(parent)?;
+    }
+
+    Ok(BufWriter::new(File::create(path)?))
+}
+
+/// Writes a looping animated GIF one image at a time, all the same size.
+pub struct GifWriter<W: Write> {
+    encoder: gif::Encoder<W>,
+    width: u16,
+    height: u16,
+    /// In hundredths of a second.
+    delay: u16,
+}
+
+impl<W: Write> GifWriter<W> {
+    pub fn new(
+        writer: W,
+        width: usize,
+        height: usize,
+        delay: Duration,
+    ) -> Result<Self, ImageError> {
+        let too_large = || ImageError::TooLarge { width, height };
+        let width = width.try_into().map_err(|_| too_large())?;
+        let height = height.try_into().map_err(|_| too_large())?;
+
+        let mut encoder = gif::Encoder::new(writer, width, height, &[])?;
+        encoder.set_repeat(gif::Repeat::Infinite)?;
+
+        Ok(Self {
+            encoder,
+            width,
+            height,
+            delay: (delay.as_millis() / 10).try_into().unwrap_or(u16::MAX),
+        })
+    }
+
+    pub fn write(&mut self, image: &Image) -> Result<(), ImageError> {
+        assert_eq!(
+            (image.width(), image.height()),
+            (self.width.into(), self.height.into()),
+            "Every frame of a GIF must be the same size"
+        );
+
+        let mut frame = gif_frame(image, self.width, self.height);
+        frame.delay = self.delay;
+        self.encoder.write_frame(&frame)?;
+        Ok(())
+    }
+}
+
+pub fn write_gif(writer: impl Write, frames: &[Image], delay: Duration) -> Result<(), ImageError> {
+    let Some(first) = frames.first() else {
+        return Ok(());
+    };
+
+    let mut gif = GifWriter::new(writer, first.width(), first.height(), delay)?;
+    frames.iter().try_for_each(|image| gif.write(image))
+}
+
+/// Puzzle states rarely use many colours, so they're indexed exactly when they fit in a palette.
+fn gif_frame(image: &Image, width: u16, height: u16) -> gif::Frame<'static> {
+    let mut palette = HashMap::new();
+    let indices = image
+        .pixels
+        .iter()
+        .map(|(_, &pixel)| {
+            let next = palette.len();
+            *palette.entry(pixel).or_insert(next)
+        })
+        .collect::<Vec<_>>();
+
+    if palette.len() > GIF_PALETTE {
+        return gif::Frame::from_rgb_speed(width, height, &image.bytes(), 10);
+    }
+
+    let mut colours = vec![Rgb::BLACK; palette.len()];
+
+    for (pixel, index) in palette {
+        colours[index] = pixel;
+    }
+
+    let colours = colours
+        .into_iter()
+        .flat_map(|Rgb(red, green, blue)| [red, green, blue])
+        .collect::<Vec<_>>();
+    let indices = indices
+        .into_iter()
+        .map(|index| index as u8)
+        .collect::<Vec<_>>();
+
+    gif::Frame::from_palette_pixels(width, height, indices, colours, None)
+}
+
+pub fn save_gif(path: &Path, frames: &[Image], delay: Duration) -> Result<(), ImageError> {
+    let mut writer = create(path)?;
+    write_gif(&mut writer, frames, delay)?;
+    Ok(writer.flush()?)
+}
+
+static EXPORT_DIR: Mutex<Option<PathBuf>> = Mutex::new(None);
+
+/// Where a day's images go when exporting to `directory`.
+pub fn day_directory(directory: &Path, year: u16, day: u8) -> PathBuf {
+    directory.join(format!("{}-day-{}", year, day))
+}
+
+/// Starts saving images passed to [`export`] into `directory`, or stops with `None`.
+pub fn export_to(directory: Option<PathBuf>) {
+    *lock() = directory;
+}
+
+/// Reads `$AOC_EXPORT_DIR`, which is unset when exporting is off.
+pub fn export_dir_from_env() -> Option<PathBuf> {
+    env::var_os(EXPORT_DIR_VAR).map(PathBuf::from)
+}
+
+pub fn is_exporting() -> bool {
+    lock().is_some()
+}
+
+fn export_path(name: &str) -> Option<PathBuf> {
+    lock().as_ref().map(|directory| directory.join(name))
+}
+
+fn exported(path: &Path, result: Result<(), ImageError>) {
+    match result {
+        Ok(()) => eprintln!("Saved {}", path.display()),
+        Err(error) => panic!("Failed to export {}: {}", path.display(), error),
+    }
+}
+
+/// Saves the image built by `image` as `name` when exporting; otherwise `image` isn't called.
+pub fn export(name: &str, image: impl FnOnce() -> Image) {
+    if let Some(path) = export_path(name) {
+        exported(&path, image().save(&path));
+    }
+}
+
+/// Saves images as an animated GIF while exporting, and does nothing otherwise.
+///
+/// Frames are written as they're recorded, so long runs don't have to fit in memory.
+pub struct Recording {
+    path: Option<PathBuf>,
+    delay: Duration,
+    gif: Option<GifWriter<BufWriter<File>>>,
+    frames: usize,
+}
+
+impl Recording {
+    /// Records into the GIF `name`, showing each frame for `delay`.
+    pub fn new(name: &str, delay: Duration) -> Self {
+        Self {
+            path: export_path(name),
+            delay,
+            gif: None,
+            frames: 0,
+        }
+    }
+
+    pub fn record(&mut self, image: impl FnOnce() -> Image) {
+        let Some(path) = &self.path else {
+            return;
+        };
+
+        let image = image();
+        let result = match &mut self.gif {
+            Some(gif) => gif.write(&image),
+            None => create(path)
+                .and_then(|writer| {
+                    GifWriter::new(writer, image.width(), image.height(), self.delay)
+                })
+                .and_then(|gif| self.gif.insert(gif).write(&image)),
+        };
+
+        if let Err(error) = result {
+            panic!("Failed to export {}: {}", path.display(), error);
+        }
+
+        self.frames += 1;
+    }
+
+    pub fn frames(&self) -> usize {
+        self.frames
+    }
+
+    /// Finishes the GIF.
+    pub fn save(mut self) {
+        if let (Some(path), Some(gif)) = (&self.path, self.gif.take()) {
+            let result = gif
+                .encoder
+                .into_inner()
+                .and_then(|mut writer| writer.flush())
+                .map_err(ImageError::from);
+
+            exported(path, result);
+        }
+    }
+}
+
+fn lock() -> std::sync::MutexGuard<'static, Option<PathBuf>> {
+    EXPORT_DIR
+        .lock()
+        .unwrap_or_else(|poisoned| poisoned.into_inner())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::viz::Cell;
+
+    fn image() -> Image {
+        Image::from_points(3, 2, [Point::new(0u8, 0), Point::new(2, 1)], Rgb::WHITE)
+    }
+
+    #[test]
+    fn ppm() {
+        let mut output = Vec::new();
+        image().write_ppm(&mut output).unwrap();
+
+        let mut expected = b"P6\n3 2\n255\n".to_vec();
+        expected.extend([255, 255, 255, 0, 0, 0, 0, 0, 0]);
+        expected.extend([0, 0, 0, 0, 0, 0, 255, 255, 255]);
+        assert_eq!(output, expected);
+    }
+
+    #[test]
+    fn png() {
+        let mut output = Vec::new();
+        image().write_png(&mut output).unwrap();
+
+        assert!(output.starts_with(b"\x89PNG\r\n\x1a\n"));
+    }
+
+    #[test]
+    fn gif() {
+        let frames = [image(), image().scaled(1), Image::new(3, 2, Rgb(1, 2, 3))];
+        let mut output = Vec::new();
+        write_gif(&mut output, &frames, Duration::from_millis(100)).unwrap();
+
+        assert!(output.starts_with(b"GIF89a"));
+        assert_eq!(output.last(), Some(&0x3b));
+    }
+
+    #[test]
+    fn scaled() {
+        let image = image().scaled(2);
+
+        assert_eq!((image.width(), image.height()), (6, 4));
+        assert_eq!(image.get((1, 1)), Some(Rgb::WHITE));
+        assert_eq!(image.get((2, 1)), Some(Rgb::BLACK));
+        assert_eq!(image.get((5, 3)), Some(Rgb::WHITE));
+    }
+
+    #[test]
+    fn from_frame() {
+        let mut frame = Frame::new(3, 1);
+        frame.set((1, 0), Cell::plain('#'));
+        frame.set((2, 0), Cell::new('@', Colour::White));
+
+        let image = Image::from_frame(&frame);
+
+        assert_eq!(image.get((0, 0)), Some(Rgb::BLACK));
+        assert_eq!(image.get((1, 0)), Some(Rgb::WHITE));
+        assert_eq!(image.get((2, 0)), Some(Rgb::WHITE));
+    }
+
+    #[test]
+    fn unknown_format() {
+        assert!(matches!(
+            image().save(Path::new("image.bmp")),
+            Err(ImageError::UnknownFormat(_))
+        ));
+    }
+
+    #[test]
+    fn not_exporting() {
+        let mut recording = Recording::new("run.gif", Duration::ZERO);
+        recording.record(|| unreachable!("Images shouldn't be built when not exporting"));
+
+        assert_eq!(recording.frames(), 0);
+        export("image.png", || unreachable!());
+    }
+}
@@ -0,0 +1,188 @@
+use std::{
+    env,
+    error::Error,
+    fmt::Display,
+    fs,
+    io::{self, Read},
+    path::{Path, PathBuf},
+};
+
+/// Overrides where inputs are loaded from; laid out as `<dir>/<year>/day-<day>`.
+pub const INPUT_DIR_VAR: &str = "AOC_INPUT_DIR";
+
+#[derive(Debug)]
+pub enum InputError {
+    Missing { year: u16, day: u8, path: PathBuf },
+    Read { path: PathBuf, source: io::Error },
+    Stdin(io::Error),
+}
+
+impl Display for InputError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Missing { year, day, path } => write!(
+                f,
+                "Missing input for {} Day {}: expected a file at {}. \
+                 Save your puzzle input there, set {} or pass an input path.",
+                year,
+                day,
+                path.display(),
+                INPUT_DIR_VAR
+            ),
+            Self::Read { path, source } => {
+                write!(f, "Failed to read input {}: {}", path.display(), source)
+            }
+            Self::Stdin(source) => write!(f, "Failed to read input from stdin: {}", source),
+        }
+    }
+}
+
+impl Error for InputError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match self {
+            Self::Missing { .. } => None,
+            Self::Read { source, .. } | Self::Stdin(source) => Some(source),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub enum InputSource {
+    /// `$AOC_INPUT_DIR` if set, otherwise the `input` file next to each day's crate.
+    #[default]
+    Default,
+    Directory(PathBuf),
+    File(PathBuf),
+    Stdin,
+}
+
+impl InputSource {
+    /// Treats `-` as stdin and anything else as a file path.
+    pub fn from_arg(value: &str) -> Self {
+        if value == "-" {
+            Self::Stdin
+        } else {
+            Self::File(PathBuf::from(value))
+        }
+    }
+
+    pub fn path(&self, year: u16, day: u8) -> Option<PathBuf> {
+        match self {
+            Self::Default => Some(match env::var_os(INPUT_DIR_VAR) {
+                Some(directory) => directory_input_path(Path::new(&directory), year, day),
+                None => repository_input_path(year, day),
+            }),
+            Self::Directory(directory) => Some(directory_input_path(directory, year, day)),
+            Self::File(path) => Some(path.clone()),
+            Self::Stdin => None,
+        }
+    }
+
+    pub fn load(&self, year: u16, day: u8) -> Result<String, InputError> {
+        match self.path(year, day) {
+            Some(path) => read_input(year, day, path),
+            None => {
+                let mut input = String::new();
+                io::stdin()
+                    .read_to_string(&mut input)
+                    .map_err(InputError::Stdin)?;
+                Ok(input)
+            }
+        }
+    }
+}
+
+fn directory_input_path(directory: &Path, year: u16, day: u8) -> PathBuf {
+    directory
+        .join(year.to_string())
+        .join(format!("day-{}", day))
+}
+
+/// `years/<year>` in this repository.
+pub fn year_directory(year: u16) -> PathBuf {
+    let repository = Path::new(env!("CARGO_MANIFEST_DIR"))
+        .parent()
+        .unwrap_or(Path::new(".."));
+
+    repository.join("years").join(year.to_string())
+}
+
+fn repository_input_path(year: u16, day: u8) -> PathBuf {
+    year_directory(year)
+        .join("days")
+        .join(format!("day-{}", day))
+        .join("input")
+}
+
+fn read_input(year: u16, day: u8, path: PathBuf) -> Result<String, InputError> {
+    fs::read_to_string(&path).map_err(|source| {
+        if source.kind() == io::ErrorKind::NotFound {
+            InputError::Missing { year, day, path }
+        } else {
+            InputError::Read { path, source }
+        }
+    })
+}
+
+pub fn load_input(year: u16, day: u8) -> Result<String, InputError> {
+    InputSource::Default.load(year, day)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn from_arg() {
+        assert_eq!(InputSource::from_arg("-"), InputSource::Stdin);
+        assert_eq!(
+            InputSource::from_arg("inputs/day-1"),
+            InputSource::File(PathBuf::from("inputs/day-1"))
+        );
+    }
+
+    #[test]
+    fn directory_layout() {
+        let source = InputSource::Directory(PathBuf::from("inputs"));
+        assert_eq!(
+            source.path(2024, 11),
+            Some(PathBuf::from("inputs/2024/day-11"))
+        );
+    }
+
+    #[test]
+    fn directory_load() {
+        let directory = env::temp_dir().join(format!("common-input-{}", std::process::id()));
+        fs::create_dir_all(directory.join("2024")).unwrap();
+        fs::write(directory.join("2024").join("day-3"), "mul(2,4)").unwrap();
+
+        let source = InputSource::Directory(directory.clone());
+        let input = source.load(2024, 3);
+        let missing = source.load(2024, 4);
+
+        fs::remove_dir_all(&directory).unwrap();
+
+        assert_eq!(input.unwrap(), "mul(2,4)");
+        assert!(matches!(
+            missing,
+            Err(InputError::Missing {
+                year: 2024,
+                day: 4,
+                ..
+            })
+        ));
+    }
+
+    #[test]
+    fn missing_message() {
+        let error = InputSource::File(PathBuf::from("does/not/exist"))
+            .load(2015, 1)
+            .unwrap_err();
+
+        assert_eq!(
+            error.to_string(),
+            "Missing input for 2015 Day 1: expected a file at does/not/exist. \
+             Save your puzzle input there, set AOC_INPUT_DIR or pass an input path."
+        );
+    }
+}
@@ -1,14 +1,63 @@
 #![feature(step_trait)]
 
+mod answers;
+mod cycle;
+mod error;
+mod examples;
+pub mod geometry;
+mod grid;
+pub mod image;
+mod input;
+mod linear;
+mod memo;
+mod number;
+pub mod parse;
+mod report;
+mod search;
+mod solution;
+mod timing;
+pub mod viz;
+
 use std::{fmt::Display, iter::Step};
 
 use num_traits::PrimInt;
 
+pub use answers::*;
+pub use cycle::*;
+pub use error::*;
+pub use examples::*;
+pub use geometry::*;
+pub use grid::*;
+pub use input::*;
+pub use linear::*;
+pub use memo::*;
+pub use number::*;
+pub use report::*;
+pub use search::*;
+pub use solution::*;
+pub use timing::*;
+
 pub fn advent_solution(year: u16, day: u8, part_one: impl Display, part_two: impl Display) {
-    println!(
-        "=== {} Day {} ===\n\nPart One:\n{}\n\nPart Two:\n{}",
-        year, day, part_one, part_two
-    );
+    let answers = [
+        (Part::One, part_one.to_string()),
+        (Part::Two, part_two.to_string()),
+    ]
+    .into_iter()
+    .map(|(part, answer)| Answer {
+        part,
+        answer,
+        duration: None,
+    })
+    .collect();
+
+    let report = Report {
+        year,
+        day,
+        parse: None,
+        answers,
+    };
+
+    print_report(&report);
 }
 
 pub fn width_height_start_2d_iter<W, H>(
@@ -32,6 +81,7 @@ where
     width_height_start_2d_iter(W::zero(), H::zero(), width, height)
 }
 
+/// Embeds `../input` at compile time; fast, but the file must exist to build.
 #[macro_export]
 macro_rules! include_input {
     ($var: ident) => {
@@ -0,0 +1,351 @@
+use std::{error::Error, fmt::Display};
+
+use crate::{extended_gcd, gcd};
+
+/// Why a system of equations has no usable solution.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum NoSolution {
+    /// The equations aren't independent, so there isn't a single solution.
+    Singular,
+    /// The equations contradict each other.
+    Inconsistent,
+    /// The only solutions need fractional values.
+    NonIntegral,
+    /// Every solution needs a negative value.
+    Negative,
+    /// Solving needs numbers that don't fit in an `i128`.
+    Overflow,
+}
+
+impl NoSolution {
+    pub(crate) fn reason(self) -> &'static str {
+        match self {
+            Self::Singular => "the equations are not independent",
+            Self::Inconsistent => "the equations contradict each other",
+            Self::NonIntegral => "no solution is a whole number",
+            Self::Negative => "every solution is negative",
+            Self::Overflow => "the numbers involved overflowed",
+        }
+    }
+}
+
+impl Display for NoSolution {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "No solution, {}", self.reason())
+    }
+}
+
+impl Error for NoSolution {}
+
+/// An exact fraction, always stored in lowest terms with a positive denominator.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Rational {
+    numerator: i128,
+    denominator: i128,
+}
+
+impl Rational {
+    pub const ZERO: Self = Self::integer(0);
+    pub const ONE: Self = Self::integer(1);
+
+    pub fn new(numerator: i128, denominator: i128) -> Self {
+        assert_ne!(denominator, 0, "Denominator must not be zero");
+
+        let divisor = gcd(numerator, denominator) * denominator.signum();
+
+        Self {
+            numerator: numerator / divisor,
+            denominator: denominator / divisor,
+        }
+    }
+
+    pub const fn integer(value: i128) -> Self {
+        Self {
+            numerator: value,
+            denominator: 1,
+        }
+    }
+
+    pub fn numerator(self) -> i128 {
+        self.numerator
+    }
+
+    pub fn denominator(self) -> i128 {
+        self.denominator
+    }
+
+    pub fn is_zero(self) -> bool {
+        self.numerator == 0
+    }
+
+    pub fn is_integer(self) -> bool {
+        self.denominator == 1
+    }
+
+    pub fn to_integer(self) -> Option<i128> {
+        self.is_integer().then_some(self.numerator)
+    }
+
+    pub fn checked_add(self, rhs: Self) -> Option<Self> {
+        let denominator = self.denominator.checked_mul(rhs.denominator)?;
+        let numerator = self
+            .numerator
+            .checked_mul(rhs.denominator)?
+            .checked_add(rhs.numerator.checked_mul(self.denominator)?)?;
+
+        Some(Self::new(numerator, denominator))
+    }
+
+    pub fn checked_sub(self, rhs: Self) -> Option<Self> {
+        self.checked_add(Self {
+            numerator: rhs.numerator.checked_neg()?,
+            denominator: rhs.denominator,
+        })
+    }
+
+    pub fn checked_mul(self, rhs: Self) -> Option<Self> {
+        // Cross-cancelling first keeps the intermediate products small.
+        let left = gcd(self.numerator, rhs.denominator).max(1);
+        let right = gcd(rhs.numerator, self.denominator).max(1);
+
+        Some(Self::new(
+            (self.numerator / left).checked_mul(rhs.numerator / right)?,
+            (self.denominator / right).checked_mul(rhs.denominator / left)?,
+        ))
+    }
+
+    /// `None` on overflow or division by zero.
+    pub fn checked_div(self, rhs: Self) -> Option<Self> {
+        if rhs.is_zero() {
+            return None;
+        }
+
+        self.checked_mul(Self::new(rhs.denominator, rhs.numerator))
+    }
+}
+
+impl From<i128> for Rational {
+    fn from(value: i128) -> Self {
+        Self::integer(value)
+    }
+}
+
+impl Display for Rational {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        if self.is_integer() {
+            write!(f, "{}", self.numerator)
+        } else {
+            write!(f, "{}/{}", self.numerator, self.denominator)
+        }
+    }
+}
+
+/// Solves the square system `matrix * x = rhs` exactly by Gauss-Jordan elimination.
+pub fn solve_linear_system(
+    matrix: &[Vec<i128>],
+    rhs: &[i128],
+) -> Result<Vec<Rational>, NoSolution> {
+    let size = rhs.len();
+    assert!(
+        matrix.len() == size && matrix.iter().all(|row| row.len() == size),
+        "Expected a {}x{} matrix",
+        size,
+        size
+    );
+
+    let mut rows = matrix
+        .iter()
+        .zip(rhs)
+        .map(|(row, &value)| {
+            row.iter()
+                .chain([&value])
+                .map(|&cell| Rational::integer(cell))
+                .collect::<Vec<_>>()
+        })
+        .collect::<Vec<_>>();
+
+    for column in 0..size {
+        let pivot = (column..size)
+            .find(|&row| !rows[row][column].is_zero())
+            .ok_or(NoSolution::Singular)?;
+
+        rows.swap(column, pivot);
+
+        let pivot_row = rows[column].clone();
+
+        for row in (0..size).filter(|&row| row != column) {
+            if rows[row][column].is_zero() {
+                continue;
+            }
+
+            let factor = rows[row][column]
+                .checked_div(pivot_row[column])
+                .ok_or(NoSolution::Overflow)?;
+
+            for (cell, &pivot_cell) in rows[row].iter_mut().zip(&pivot_row).skip(column) {
+                *cell = factor
+                    .checked_mul(pivot_cell)
+                    .and_then(|value| cell.checked_sub(value))
+                    .ok_or(NoSolution::Overflow)?;
+            }
+        }
+    }
+
+    rows.iter()
+        .enumerate()
+        .map(|(index, row)| row[size].checked_div(row[index]))
+        .collect::<Option<_>>()
+        .ok_or(NoSolution::Overflow)
+}
+
+/// Like [`solve_linear_system`], requiring every value to be a whole number.
+pub fn solve_integer_system(matrix: &[Vec<i128>], rhs: &[i128]) -> Result<Vec<i128>, NoSolution> {
+    solve_linear_system(matrix, rhs)?
+        .into_iter()
+        .map(Rational::to_integer)
+        .collect::<Option<_>>()
+        .ok_or(NoSolution::NonIntegral)
+}
+
+/// The non-negative `(x, y)` with `a * x + b * y == c` that minimises
+/// `x_cost * x + y_cost * y`, for non-negative `a` and `b`.
+///
+/// A zero coefficient leaves its variable free, which is then left at zero.
+pub fn min_cost_diophantine(
+    a: i128,
+    b: i128,
+    c: i128,
+    x_cost: i128,
+    y_cost: i128,
+) -> Result<(i128, i128), NoSolution> {
+    assert!(a >= 0 && b >= 0, "Coefficients must not be negative");
+
+    match (a, b) {
+        (0, 0) if c == 0 => return Ok((0, 0)),
+        (0, 0) => return Err(NoSolution::Inconsistent),
+        (0, _) => return single_diophantine(b, c).map(|y| (0, y)),
+        (_, 0) => return single_diophantine(a, c).map(|x| (x, 0)),
+        _ => (),
+    }
+
+    let (divisor, x_factor, y_factor) = extended_gcd(a, b);
+
+    if c % divisor != 0 {
+        return Err(NoSolution::NonIntegral);
+    }
+
+    let scale = c / divisor;
+    let x_base = x_factor.checked_mul(scale).ok_or(NoSolution::Overflow)?;
+    let y_base = y_factor.checked_mul(scale).ok_or(NoSolution::Overflow)?;
+
+    // Every solution is `(x_base + k * x_step, y_base - k * y_step)` for some integer `k`.
+    let (x_step, y_step) = (b / divisor, a / divisor);
+    let lowest = -x_base.div_euclid(x_step);
+    let highest = y_base.div_euclid(y_step);
+
+    if lowest > highest {
+        return Err(NoSolution::Negative);
+    }
+
+    // Cost changes by the same amount with every step of `k`, so an end is always cheapest.
+    let cost_change = x_cost * x_step - y_cost * y_step;
+    let k = if cost_change > 0 { lowest } else { highest };
+
+    let x = k
+        .checked_mul(x_step)
+        .and_then(|offset| x_base.checked_add(offset));
+    let y = k
+        .checked_mul(y_step)
+        .and_then(|offset| y_base.checked_sub(offset));
+
+    x.zip(y).ok_or(NoSolution::Overflow)
+}
+
+/// The non-negative `x` with `a * x == c`, for positive `a`.
+fn single_diophantine(a: i128, c: i128) -> Result<i128, NoSolution> {
+    if c % a != 0 {
+        Err(NoSolution::NonIntegral)
+    } else if c < 0 {
+        Err(NoSolution::Negative)
+    } else {
+        Ok(c / a)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn rational() {
+        let half = Rational::new(2, 4);
+
+        assert_eq!(half, Rational::new(-1, -2));
+        assert_eq!((half.numerator(), half.denominator()), (1, 2));
+        assert_eq!(half.checked_add(half), Some(Rational::ONE));
+        assert_eq!(half.checked_sub(Rational::ONE), Some(Rational::new(1, -2)));
+        assert_eq!(
+            half.checked_mul(Rational::new(2, 3)),
+            Some(Rational::new(1, 3))
+        );
+        assert_eq!(half.checked_div(Rational::ZERO), None);
+        assert_eq!(Rational::new(-3, 6).to_string(), "-1/2");
+        assert_eq!(
+            Rational::integer(i128::MAX).checked_add(Rational::ONE),
+            None
+        );
+    }
+
+    #[test]
+    fn linear_system() {
+        let matrix = [vec![2, 1, -1], vec![-3, -1, 2], vec![-2, 1, 2]];
+
+        assert_eq!(
+            solve_integer_system(&matrix, &[8, -11, -3]),
+            Ok(vec![2, 3, -1])
+        );
+        assert_eq!(
+            solve_linear_system(&[vec![2, 0], vec![0, 4]], &[1, 1]),
+            Ok(vec![Rational::new(1, 2), Rational::new(1, 4)])
+        );
+        assert_eq!(
+            solve_integer_system(&[vec![2, 0], vec![0, 4]], &[1, 1]),
+            Err(NoSolution::NonIntegral)
+        );
+        assert_eq!(
+            solve_linear_system(&[vec![1, 2], vec![2, 4]], &[3, 6]),
+            Err(NoSolution::Singular)
+        );
+        assert_eq!(
+            solve_integer_system(&[vec![0, 1], vec![1, 0]], &[5, 7]),
+            Ok(vec![7, 5])
+        );
+    }
+
+    #[test]
+    fn diophantine() {
+        assert_eq!(min_cost_diophantine(3, 5, 22, 1, 1), Ok((4, 2)));
+        assert_eq!(min_cost_diophantine(3, 5, 22, 1, 10), Ok((4, 2)));
+        assert_eq!(min_cost_diophantine(1, 2, 6, 1, 1), Ok((0, 3)));
+        assert_eq!(min_cost_diophantine(1, 2, 6, 1, 3), Ok((6, 0)));
+        assert_eq!(
+            min_cost_diophantine(4, 6, 7, 1, 1),
+            Err(NoSolution::NonIntegral)
+        );
+        assert_eq!(
+            min_cost_diophantine(3, 5, 7, 1, 1),
+            Err(NoSolution::Negative)
+        );
+        assert_eq!(min_cost_diophantine(2, 0, 6, 3, 1), Ok((3, 0)));
+        assert_eq!(min_cost_diophantine(0, 4, 12, 3, 1), Ok((0, 3)));
+        assert_eq!(
+            min_cost_diophantine(0, 4, 13, 3, 1),
+            Err(NoSolution::NonIntegral)
+        );
+        assert_eq!(min_cost_diophantine(0, 0, 0, 3, 1), Ok((0, 0)));
+        assert_eq!(
+            min_cost_diophantine(0, 0, 5, 3, 1),
+            Err(NoSolution::Inconsistent)
+        );
+    }
+}
@@ -0,0 +1,185 @@
+use std::{collections::HashMap, fmt::Display, hash::Hash};
+
+/// Handed to a memoized function so it can solve smaller problems through the same cache.
+pub type Recurse<'a, K, V> = dyn FnMut(K) -> V + 'a;
+
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct MemoStats {
+    pub hits: u64,
+    pub misses: u64,
+}
+
+impl MemoStats {
+    pub fn lookups(&self) -> u64 {
+        self.hits + self.misses
+    }
+
+    /// Between 0 and 1, or 0 before any lookups.
+    pub fn hit_rate(&self) -> f64 {
+        if self.lookups() == 0 {
+            0.0
+        } else {
+            self.hits as f64 / self.lookups() as f64
+        }
+    }
+}
+
+impl Display for MemoStats {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "{} hits, {} misses ({:.1}% hit rate)",
+            self.hits,
+            self.misses,
+            self.hit_rate() * 100.0
+        )
+    }
+}
+
+/// Caches the results of a recursive function, counting how often the cache helped.
+///
+/// ```
+/// use common::{Memo, Recurse};
+///
+/// fn fibonacci(fibonacci: &mut Recurse<u64, u64>, n: u64) -> u64 {
+///     match n {
+///         0 | 1 => n,
+///         n => fibonacci(n - 1) + fibonacci(n - 2),
+///     }
+/// }
+///
+/// let mut memo = Memo::new();
+/// assert_eq!(memo.get(90, fibonacci), 2_880_067_194_370_816_120);
+/// assert_eq!(memo.stats().misses, 91);
+/// ```
+#[derive(Debug, Clone)]
+pub struct Memo<K, V> {
+    cache: HashMap<K, V>,
+    stats: MemoStats,
+}
+
+impl<K: Clone + Eq + Hash, V: Clone> Memo<K, V> {
+    pub fn new() -> Self {
+        Self::with_capacity(0)
+    }
+
+    pub fn with_capacity(capacity: usize) -> Self {
+        Self {
+            cache: HashMap::with_capacity(capacity),
+            stats: MemoStats::default(),
+        }
+    }
+
+    /// The value of `f` at `key`; `f` gets a [`Recurse`] to call itself with.
+    ///
+    /// The cache outlives the call, so later calls reuse earlier results as long as they pass
+    /// the same function.
+    pub fn get(&mut self, key: K, f: impl Fn(&mut Recurse<K, V>, K) -> V) -> V {
+        self.lookup(key, &f)
+    }
+
+    fn lookup(&mut self, key: K, f: &dyn Fn(&mut Recurse<K, V>, K) -> V) -> V {
+        if let Some(value) = self.cache.get(&key) {
+            self.stats.hits += 1;
+            return value.clone();
+        }
+
+        self.stats.misses += 1;
+
+        let value = f(&mut |next| self.lookup(next, f), key.clone());
+        self.cache.insert(key, value.clone());
+        value
+    }
+
+    pub fn cached(&self, key: &K) -> Option<&V> {
+        self.cache.get(key)
+    }
+
+    pub fn stats(&self) -> MemoStats {
+        self.stats
+    }
+
+    pub fn len(&self) -> usize {
+        self.cache.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.cache.is_empty()
+    }
+
+    /// Forgets every value, keeping the statistics.
+    pub fn clear(&mut self) {
+        self.cache.clear();
+    }
+}
+
+impl<K: Clone + Eq + Hash, V: Clone> Default for Memo<K, V> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Ways to climb `steps` stairs taking one or two at a time.
+    fn stairs(stairs: &mut Recurse<u32, u64>, steps: u32) -> u64 {
+        match steps {
+            0 | 1 => 1,
+            steps => stairs(steps - 1) + stairs(steps - 2),
+        }
+    }
+
+    #[test]
+    fn recursion() {
+        let mut memo = Memo::new();
+
+        assert_eq!(memo.get(10, stairs), 89);
+        assert_eq!(memo.len(), 11);
+        assert_eq!(
+            memo.stats(),
+            MemoStats {
+                hits: 8,
+                misses: 11
+            }
+        );
+
+        assert_eq!(memo.get(11, stairs), 144);
+        assert_eq!(memo.stats().hits, 10);
+        assert_eq!(memo.cached(&5), Some(&8));
+    }
+
+    #[test]
+    fn stats() {
+        let stats = MemoStats { hits: 3, misses: 1 };
+
+        assert_eq!(stats.lookups(), 4);
+        assert_eq!(stats.hit_rate(), 0.75);
+        assert_eq!(stats.to_string(), "3 hits, 1 misses (75.0% hit rate)");
+        assert_eq!(MemoStats::default().hit_rate(), 0.0);
+    }
+
+    #[test]
+    fn closures() {
+        let limit = 3;
+        let mut memo = Memo::new();
+
+        let capped = memo.get(
+            10,
+            |capped, n: u32| {
+                if n <= limit {
+                    n
+                } else {
+                    capped(n - 1)
+                }
+            },
+        );
+
+        assert_eq!(capped, 3);
+
+        memo.clear();
+        assert!(memo.is_empty());
+        assert_eq!(memo.stats().misses, 8);
+    }
+}
@@ -0,0 +1,298 @@
+use num_traits::{checked_pow, Euclid, PrimInt, Signed};
+
+/// Greatest common divisor; never negative, and `gcd(0, 0)` is `0`.
+///
+/// Panics if the result would be `-T::min_value()`, which doesn't fit in a signed `T`, as with
+/// `gcd(i32::MIN, 0)`.
+pub fn gcd<T: PrimInt>(a: T, b: T) -> T {
+    let (mut a, mut b) = (a, b);
+
+    while !b.is_zero() {
+        (a, b) = (b, a % b);
+    }
+
+    if a < T::zero() {
+        T::zero()
+            .checked_sub(&a)
+            .expect("Greatest common divisor overflowed")
+    } else {
+        a
+    }
+}
+
+/// Least common multiple, or `None` if it overflows `T`.
+pub fn checked_lcm<T: PrimInt>(a: T, b: T) -> Option<T> {
+    if a.is_zero() || b.is_zero() {
+        return Some(T::zero());
+    }
+
+    let lcm = (a / gcd(a, b)).checked_mul(&b)?;
+
+    if lcm < T::zero() {
+        T::zero().checked_sub(&lcm)
+    } else {
+        Some(lcm)
+    }
+}
+
+pub fn lcm<T: PrimInt>(a: T, b: T) -> T {
+    checked_lcm(a, b).expect("Least common multiple overflowed")
+}
+
+/// Returns `(g, x, y)` such that `a * x + b * y == g == gcd(a, b)`.
+pub fn extended_gcd<T: PrimInt + Signed>(a: T, b: T) -> (T, T, T) {
+    let (mut old_r, mut r) = (a, b);
+    let (mut old_x, mut x) = (T::one(), T::zero());
+    let (mut old_y, mut y) = (T::zero(), T::one());
+
+    while !r.is_zero() {
+        let quotient = old_r / r;
+        (old_r, r) = (r, old_r - quotient * r);
+        (old_x, x) = (x, old_x - quotient * x);
+        (old_y, y) = (y, old_y - quotient * y);
+    }
+
+    if old_r < T::zero() {
+        (-old_r, -old_x, -old_y)
+    } else {
+        (old_r, old_x, old_y)
+    }
+}
+
+/// The `x` in `0..modulus` with `a * x ≡ 1`, if `a` and `modulus` are coprime.
+pub fn mod_inverse<T: PrimInt + Signed + Euclid>(a: T, modulus: T) -> Option<T> {
+    let (g, x, _) = extended_gcd(a.rem_euclid(&modulus), modulus);
+
+    g.is_one().then(|| x.rem_euclid(&modulus))
+}
+
+/// `a * b % modulus` without overflowing, for `a` and `b` already below `modulus`.
+fn mul_mod(a: u128, b: u128, modulus: u128) -> u128 {
+    if let Some(product) = a.checked_mul(b) {
+        return product % modulus;
+    }
+
+    let (mut a, mut b, mut result) = (a, b, 0u128);
+
+    while b > 0 {
+        if b & 1 == 1 {
+            result = add_mod(result, a, modulus);
+        }
+
+        a = add_mod(a, a, modulus);
+        b >>= 1;
+    }
+
+    result
+}
+
+fn add_mod(a: u128, b: u128, modulus: u128) -> u128 {
+    if a >= modulus - b {
+        a - (modulus - b)
+    } else {
+        a + b
+    }
+}
+
+fn to_unsigned_residue<T: PrimInt>(value: T, modulus: u128) -> u128 {
+    match value.to_u128() {
+        Some(value) => value % modulus,
+        None => {
+            let value = value.to_i128().expect("Integers are at most 128 bits");
+            value.rem_euclid(modulus as i128) as u128
+        }
+    }
+}
+
+/// `base` to the power of `exponent`, modulo a positive `modulus`; negative bases wrap into range.
+pub fn mod_pow<T: PrimInt>(base: T, exponent: u64, modulus: T) -> T {
+    assert!(modulus > T::zero(), "Modulus must be positive");
+
+    let modulus_wide = modulus.to_u128().unwrap();
+    let mut base = to_unsigned_residue(base, modulus_wide);
+    let mut exponent = exponent;
+    let mut result = 1 % modulus_wide;
+
+    while exponent > 0 {
+        if exponent & 1 == 1 {
+            result = mul_mod(result, base, modulus_wide);
+        }
+
+        base = mul_mod(base, base, modulus_wide);
+        exponent >>= 1;
+    }
+
+    T::from(result).unwrap()
+}
+
+/// Solves `x ≡ remainder (mod modulus)` for every pair, returning `(x, lcm of moduli)`.
+///
+/// Moduli needn't be coprime; `None` means the congruences contradict each other or the
+/// combined modulus doesn't fit in `T`.
+pub fn crt<T: PrimInt>(congruences: &[(T, T)]) -> Option<(T, T)> {
+    let mut remainder = 0i128;
+    let mut modulus = 1i128;
+
+    for &(next_remainder, next_modulus) in congruences {
+        let next_modulus = next_modulus.to_i128()?;
+        assert!(next_modulus > 0, "Moduli must be positive");
+        let next_remainder = next_remainder.to_i128()?.rem_euclid(next_modulus);
+
+        let (g, x, _) = extended_gcd(modulus, next_modulus);
+        let difference = next_remainder - remainder;
+
+        if difference % g != 0 {
+            return None;
+        }
+
+        let step = next_modulus / g;
+        let factor = (difference / g).rem_euclid(step);
+        let multiplier = mul_mod(factor as u128, x.rem_euclid(step) as u128, step as u128) as i128;
+
+        let combined = modulus.checked_mul(step)?;
+        remainder = modulus
+            .checked_mul(multiplier)?
+            .checked_add(remainder)?
+            .rem_euclid(combined);
+        modulus = combined;
+    }
+
+    Some((T::from(remainder)?, T::from(modulus)?))
+}
+
+/// The largest `r` with `r.pow(k) <= n`, for non-negative `n` and `k > 0`.
+pub fn integer_root<T: PrimInt>(n: T, k: u32) -> T {
+    assert!(n >= T::zero(), "Cannot take the root of a negative number");
+    assert!(k > 0, "The zeroth root is undefined");
+
+    let fits = |root: T| checked_pow(root, k as usize).is_some_and(|power| power <= n);
+
+    let estimate = n.to_f64().unwrap().powf(1.0 / k as f64);
+    let mut root = T::from(estimate).unwrap_or(n);
+
+    while !fits(root) {
+        root = root - T::one();
+    }
+
+    while root.checked_add(&T::one()).is_some_and(&fits) {
+        root = root + T::one();
+    }
+
+    root
+}
+
+pub fn integer_sqrt<T: PrimInt>(n: T) -> T {
+    integer_root(n, 2)
+}
+
+pub fn is_perfect_square<T: PrimInt>(n: T) -> bool {
+    n >= T::zero() && integer_sqrt(n).pow(2) == n
+}
+
+#[cfg(test)]
+mod tests {
+    use proptest::prelude::*;
+
+    use super::*;
+
+    #[test]
+    fn known_values() {
+        assert_eq!(gcd(12, 18), 6);
+        assert_eq!(gcd(-12, 18), 6);
+        assert_eq!(gcd(0u8, 0), 0);
+        assert_eq!(lcm(4, 6), 12);
+        assert_eq!(checked_lcm(u8::MAX, 254), None);
+        assert_eq!(extended_gcd(240, 46), (2, -9, 47));
+        assert_eq!(mod_inverse(3, 11), Some(4));
+        assert_eq!(mod_inverse(2, 4), None);
+        assert_eq!(mod_pow(4u32, 13, 497), 445);
+        assert_eq!(mod_pow(-2i32, 3, 5), 2);
+        assert_eq!(mod_pow(u64::MAX - 1, 2, u64::MAX), 1);
+        assert_eq!(crt(&[(2, 3), (3, 5), (2, 7)]), Some((23, 105)));
+        assert_eq!(crt(&[(1, 4), (3, 6)]), Some((9, 12)));
+        assert_eq!(crt(&[(1, 4), (2, 6)]), None);
+        assert_eq!(
+            crt(&[(1, i64::MAX), (2, i64::MAX - 2), (3, i64::MAX - 4)]),
+            None
+        );
+        assert_eq!(integer_sqrt(99), 9);
+        assert_eq!(integer_root(u64::MAX, 2), u32::MAX as u64);
+        assert_eq!(integer_root(1_000_000_000_000i64, 3), 10_000);
+        assert!(is_perfect_square(144u16));
+        assert!(!is_perfect_square(-4));
+    }
+
+    #[test]
+    #[should_panic(expected = "Greatest common divisor overflowed")]
+    fn gcd_min_value() {
+        gcd(i32::MIN, 0);
+    }
+
+    proptest! {
+        #[test]
+        fn gcd_divides(a in -10_000i64..10_000, b in -10_000i64..10_000) {
+            let g = gcd(a, b);
+
+            prop_assert!(g >= 0);
+            if g != 0 {
+                prop_assert_eq!(a % g, 0);
+                prop_assert_eq!(b % g, 0);
+                prop_assert_eq!(gcd(a / g, b / g), 1);
+            }
+        }
+
+        #[test]
+        fn lcm_is_common_multiple(a in 1u64..100_000, b in 1u64..100_000) {
+            let l = lcm(a, b);
+
+            prop_assert_eq!(l % a, 0);
+            prop_assert_eq!(l % b, 0);
+            prop_assert_eq!(l * gcd(a, b), a * b);
+        }
+
+        #[test]
+        fn bezout(a in -100_000i64..100_000, b in -100_000i64..100_000) {
+            let (g, x, y) = extended_gcd(a, b);
+
+            prop_assert_eq!(g, gcd(a, b));
+            prop_assert_eq!(a * x + b * y, g);
+        }
+
+        #[test]
+        fn inverse(a in -1_000i64..1_000, modulus in 2i64..1_000) {
+            match mod_inverse(a, modulus) {
+                Some(inverse) => {
+                    prop_assert!((0..modulus).contains(&inverse));
+                    prop_assert_eq!((a * inverse).rem_euclid(modulus), 1);
+                }
+                None => prop_assert_ne!(gcd(a, modulus), 1),
+            }
+        }
+
+        #[test]
+        fn power(base in any::<u32>(), exponent in 0u64..64, modulus in 1u64..u64::MAX) {
+            let expected = (0..exponent).fold(1 % modulus as u128, |result, _| {
+                result * base as u128 % modulus as u128
+            });
+
+            prop_assert_eq!(mod_pow(base as u64, exponent, modulus) as u128, expected);
+        }
+
+        #[test]
+        fn chinese_remainder(x in 0u64..1_000_000, moduli in prop::collection::vec(1u64..200, 1..5)) {
+            let congruences = moduli.iter().map(|&m| (x % m, m)).collect::<Vec<_>>();
+            let (solution, modulus) = crt(&congruences).unwrap();
+
+            prop_assert_eq!(modulus, moduli.iter().fold(1, |l, &m| lcm(l, m)));
+            prop_assert_eq!(solution, x % modulus);
+        }
+
+        #[test]
+        fn roots(n in any::<u64>(), k in 1u32..6) {
+            let root = integer_root(n, k);
+
+            prop_assert!(root.pow(k) <= n);
+            prop_assert!((root + 1).checked_pow(k).is_none_or(|power| power > n));
+        }
+    }
+}
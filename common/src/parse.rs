@@ -0,0 +1,343 @@
+use std::{any::type_name, error::Error, fmt::Display, str::FromStr};
+
+/// The unparsed remainder of a puzzle input, remembering where it came from for errors.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Input<'a> {
+    source: &'a str,
+    offset: usize,
+}
+
+impl<'a> Input<'a> {
+    pub fn new(source: &'a str) -> Self {
+        Self { source, offset: 0 }
+    }
+
+    pub fn rest(self) -> &'a str {
+        &self.source[self.offset..]
+    }
+
+    pub fn is_empty(self) -> bool {
+        self.rest().is_empty()
+    }
+
+    /// Skips `bytes` bytes of the remainder.
+    pub fn advance(self, bytes: usize) -> Self {
+        Self {
+            offset: self.offset + bytes,
+            ..self
+        }
+    }
+
+    /// `(line, column)` of the next character, both counting from 1.
+    pub fn location(self) -> (usize, usize) {
+        let before = &self.source[..self.offset];
+        let line_start = before.rfind('\n').map_or(0, |newline| newline + 1);
+
+        (
+            before.matches('\n').count() + 1,
+            before[line_start..].chars().count() + 1,
+        )
+    }
+
+    /// An error at this point in the input.
+    pub fn error(self, expected: impl Into<String>) -> ParseError {
+        let (line, column) = self.location();
+        let rest = self.rest();
+
+        let found = match rest.chars().next() {
+            None => "end of input".to_string(),
+            Some('\n') => "end of line".to_string(),
+            Some(next) if next.is_whitespace() => format!("{:?}", next),
+            Some(_) => {
+                let token = rest
+                    .chars()
+                    .take_while(|character| !character.is_whitespace())
+                    .take(16)
+                    .collect::<String>();
+
+                format!("'{}'", token)
+            }
+        };
+
+        ParseError {
+            line,
+            column,
+            expected: expected.into(),
+            found,
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ParseError {
+    pub line: usize,
+    pub column: usize,
+    pub expected: String,
+    pub found: String,
+}
+
+impl Display for ParseError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "Expected {} at line {}, column {}, found {}",
+            self.expected, self.line, self.column, self.found
+        )
+    }
+}
+
+impl Error for ParseError {}
+
+/// The parsed value and the input left after it.
+pub type ParseResult<'a, T> = Result<(T, Input<'a>), ParseError>;
+
+pub trait Parser<'a, T> {
+    fn parse(&self, input: Input<'a>) -> ParseResult<'a, T>;
+}
+
+impl<'a, T, F> Parser<'a, T> for F
+where
+    F: Fn(Input<'a>) -> ParseResult<'a, T>,
+{
+    fn parse(&self, input: Input<'a>) -> ParseResult<'a, T> {
+        self(input)
+    }
+}
+
+/// Runs `parser` over all of `raw`, ignoring trailing whitespace.
+pub fn parse_all<'a, T>(raw: &'a str, parser: impl Parser<'a, T>) -> Result<T, ParseError> {
+    let (value, rest) = parser.parse(Input::new(raw.trim_end()))?;
+
+    if rest.is_empty() {
+        Ok(value)
+    } else {
+        Err(rest.error("end of input"))
+    }
+}
+
+pub fn literal<'a>(expected: &'static str) -> impl Parser<'a, &'a str> {
+    move |input: Input<'a>| {
+        if input.rest().starts_with(expected) {
+            Ok((
+                &input.rest()[..expected.len()],
+                input.advance(expected.len()),
+            ))
+        } else {
+            Err(input.error(format!("{:?}", expected)))
+        }
+    }
+}
+
+/// Zero or more spaces or tabs, but not newlines.
+pub fn spaces<'a>() -> impl Parser<'a, &'a str> {
+    move |input: Input<'a>| {
+        let rest = input.rest();
+        let length = rest.len() - rest.trim_start_matches([' ', '\t']).len();
+
+        Ok((&rest[..length], input.advance(length)))
+    }
+}
+
+fn number<'a, T: FromStr>(input: Input<'a>, sign: bool) -> ParseResult<'a, T> {
+    let rest = input.rest();
+    let sign_length = usize::from(sign && rest.starts_with(['-', '+']));
+    let digits = rest[sign_length..]
+        .bytes()
+        .take_while(u8::is_ascii_digit)
+        .count();
+
+    if digits == 0 {
+        return Err(input.advance(sign_length).error("a digit"));
+    }
+
+    let length = sign_length + digits;
+
+    match rest[..length].parse() {
+        Ok(value) => Ok((value, input.advance(length))),
+        Err(_) => Err(input.error(format!("a number that fits in {}", type_name::<T>()))),
+    }
+}
+
+/// Digits with no sign.
+pub fn unsigned<'a, T: FromStr>() -> impl Parser<'a, T> {
+    move |input: Input<'a>| number(input, false)
+}
+
+/// Digits with an optional leading `-` or `+`.
+pub fn signed<'a, T: FromStr>() -> impl Parser<'a, T> {
+    move |input: Input<'a>| number(input, true)
+}
+
+pub fn map<'a, T, U>(parser: impl Parser<'a, T>, f: impl Fn(T) -> U) -> impl Parser<'a, U> {
+    move |input: Input<'a>| {
+        let (value, rest) = parser.parse(input)?;
+        Ok((f(value), rest))
+    }
+}
+
+pub fn pair<'a, A, B>(
+    first: impl Parser<'a, A>,
+    second: impl Parser<'a, B>,
+) -> impl Parser<'a, (A, B)> {
+    move |input: Input<'a>| {
+        let (a, rest) = first.parse(input)?;
+        let (b, rest) = second.parse(rest)?;
+
+        Ok(((a, b), rest))
+    }
+}
+
+/// `first`, then `separator`, then `second`, keeping both sides.
+pub fn separated_pair<'a, A, B, S>(
+    first: impl Parser<'a, A>,
+    separator: impl Parser<'a, S>,
+    second: impl Parser<'a, B>,
+) -> impl Parser<'a, (A, B)> {
+    pair(first, preceded(separator, second))
+}
+
+/// `parser` after `prefix`, discarding the prefix.
+pub fn preceded<'a, P, T>(
+    prefix: impl Parser<'a, P>,
+    parser: impl Parser<'a, T>,
+) -> impl Parser<'a, T> {
+    map(pair(prefix, parser), |(_, value)| value)
+}
+
+/// `parser` followed by `suffix`, discarding the suffix.
+pub fn terminated<'a, T, S>(
+    parser: impl Parser<'a, T>,
+    suffix: impl Parser<'a, S>,
+) -> impl Parser<'a, T> {
+    map(pair(parser, suffix), |(value, _)| value)
+}
+
+/// `Some` if `parser` matches, otherwise `None` without consuming anything.
+pub fn optional<'a, T>(parser: impl Parser<'a, T>) -> impl Parser<'a, Option<T>> {
+    move |input: Input<'a>| match parser.parse(input) {
+        Ok((value, rest)) => Ok((Some(value), rest)),
+        Err(_) => Ok((None, input)),
+    }
+}
+
+/// One or more `item`s between `separator`s.
+///
+/// Once a separator matches another item must follow, so a bad item is reported where it is
+/// rather than as unexpected trailing input.
+pub fn separated<'a, T, S>(
+    item: impl Parser<'a, T>,
+    separator: impl Parser<'a, S>,
+) -> impl Parser<'a, Vec<T>> {
+    move |input: Input<'a>| {
+        let (first, mut rest) = item.parse(input)?;
+        let mut items = vec![first];
+
+        while let Ok((_, after_separator)) = separator.parse(rest) {
+            let (next, after_item) = item.parse(after_separator)?;
+            items.push(next);
+            rest = after_item;
+        }
+
+        Ok((items, rest))
+    }
+}
+
+/// A single newline, but not the start of a blank line.
+pub fn line_break<'a>() -> impl Parser<'a, &'a str> {
+    move |input: Input<'a>| match input.rest().strip_prefix('\n') {
+        Some(after) if !after.starts_with('\n') => Ok(("\n", input.advance(1))),
+        _ => Err(input.error("a new line")),
+    }
+}
+
+/// One `item` per line, stopping at a blank line.
+pub fn lines<'a, T>(item: impl Parser<'a, T>) -> impl Parser<'a, Vec<T>> {
+    separated(item, line_break())
+}
+
+/// `item`s separated by blank lines.
+pub fn blocks<'a, T>(item: impl Parser<'a, T>) -> impl Parser<'a, Vec<T>> {
+    separated(item, literal("\n\n"))
+}
+
+/// `key=value`, such as the `p=0,4` in `p=0,4 v=3,-3`.
+pub fn key_value<'a, T>(key: &'static str, value: impl Parser<'a, T>) -> impl Parser<'a, T> {
+    preceded(pair(literal(key), literal("=")), value)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn numbers() {
+        assert_eq!(parse_all("42", unsigned::<u8>()), Ok(42));
+        assert_eq!(parse_all("-42", signed::<i32>()), Ok(-42));
+        assert_eq!(parse_all("+7", signed::<i32>()), Ok(7));
+        assert_eq!(
+            parse_all("-42", unsigned::<u32>()).unwrap_err().expected,
+            "a digit"
+        );
+        assert_eq!(
+            parse_all("300", unsigned::<u8>()).unwrap_err().to_string(),
+            "Expected a number that fits in u8 at line 1, column 1, found '300'"
+        );
+    }
+
+    #[test]
+    fn sequences() {
+        let numbers = separated(unsigned::<u32>(), pair(literal(","), spaces()));
+
+        assert_eq!(parse_all("1, 2,3", numbers), Ok(vec![1, 2, 3]));
+        assert_eq!(
+            parse_all("1\n2\n3\n", lines(unsigned::<u32>())),
+            Ok(vec![1, 2, 3])
+        );
+        assert_eq!(
+            parse_all(
+                "1 2\n3\n\n4",
+                blocks(lines(separated(unsigned::<u8>(), literal(" "))))
+            ),
+            Ok(vec![vec![vec![1, 2], vec![3]], vec![vec![4]]])
+        );
+        assert_eq!(
+            parse_all("x5", pair(optional(literal("x")), unsigned::<u8>())),
+            Ok((Some("x"), 5))
+        );
+    }
+
+    #[test]
+    fn key_values() {
+        let coordinates = || separated_pair(signed::<i8>(), literal(","), signed::<i8>());
+        let robot = separated_pair(
+            key_value("p", coordinates()),
+            literal(" "),
+            key_value("v", coordinates()),
+        );
+
+        assert_eq!(parse_all("p=0,4 v=3,-3", robot), Ok(((0, 4), (3, -3))));
+    }
+
+    #[test]
+    fn error_location() {
+        let error = parse_all(
+            "1,2\n3,x\n",
+            lines(separated(unsigned::<u8>(), literal(","))),
+        )
+        .unwrap_err();
+
+        assert_eq!((error.line, error.column), (2, 3));
+        assert_eq!(error.found, "'x'");
+
+        let error =
+            parse_all("1,2\n3 4", lines(separated(unsigned::<u8>(), literal(",")))).unwrap_err();
+
+        assert_eq!((error.line, error.column), (2, 2));
+        assert_eq!(error.expected, "end of input");
+        assert_eq!(error.found, "' '");
+        assert_eq!(
+            parse_all("ab", literal("abc")).unwrap_err().to_string(),
+            "Expected \"abc\" at line 1, column 1, found 'ab'"
+        );
+    }
+}
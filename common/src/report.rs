@@ -0,0 +1,325 @@
+use std::{
+    env,
+    error::Error,
+    fmt::Display,
+    io::{self, Write},
+    str::FromStr,
+    time::Duration,
+};
+
+use serde_json::json;
+
+use crate::Part;
+
+/// Selects the output format outside the runner, e.g. for `solve_main`.
+pub const OUTPUT_FORMAT_VAR: &str = "AOC_OUTPUT_FORMAT";
+
+const CSV_HEADER: &str = "year,day,part,answer,duration";
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Answer {
+    pub part: Part,
+    pub answer: String,
+    pub duration: Option<Duration>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Report {
+    pub year: u16,
+    pub day: u8,
+    pub parse: Option<Duration>,
+    pub answers: Vec<Answer>,
+}
+
+impl Display for Report {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "=== {} Day {} ===", self.year, self.day)?;
+
+        if let Some(parse) = self.parse {
+            write!(f, "\n\nParse ({:.2?})", parse)?;
+        }
+
+        for answer in &self.answers {
+            write!(f, "\n\nPart {}", answer.part)?;
+
+            if let Some(duration) = answer.duration {
+                write!(f, " ({:.2?})", duration)?;
+            }
+
+            write!(f, ":\n{}", answer.answer)?;
+        }
+
+        Ok(())
+    }
+}
+
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum OutputFormat {
+    #[default]
+    Text,
+    /// An array of `{year, day, part, answer, duration}` objects.
+    Json,
+    /// A `year,day,part,answer,duration` header, then one row per answer.
+    Csv,
+}
+
+impl OutputFormat {
+    /// Reads `$AOC_OUTPUT_FORMAT`, defaulting to text when it is unset.
+    pub fn from_env() -> Result<Self, UnknownFormat> {
+        match env::var(OUTPUT_FORMAT_VAR) {
+            Ok(value) => value.parse(),
+            Err(_) => Ok(Self::default()),
+        }
+    }
+}
+
+impl FromStr for OutputFormat {
+    type Err = UnknownFormat;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.to_ascii_lowercase().as_str() {
+            "text" => Ok(Self::Text),
+            "json" => Ok(Self::Json),
+            "csv" => Ok(Self::Csv),
+            _ => Err(UnknownFormat(s.to_string())),
+        }
+    }
+}
+
+impl Display for OutputFormat {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Text => write!(f, "text"),
+            Self::Json => write!(f, "json"),
+            Self::Csv => write!(f, "csv"),
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct UnknownFormat(pub String);
+
+impl Display for UnknownFormat {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "Unknown output format '{}'; expected text, json or csv",
+            self.0
+        )
+    }
+}
+
+impl Error for UnknownFormat {}
+
+/// Writes a stream of reports, keeping separators, headers and brackets in one place.
+///
+/// Durations are written in nanoseconds, or left empty when a report is untimed.
+pub struct ReportWriter<W: Write> {
+    out: W,
+    format: OutputFormat,
+    written: usize,
+}
+
+impl<W: Write> ReportWriter<W> {
+    pub fn new(out: W, format: OutputFormat) -> Self {
+        Self {
+            out,
+            format,
+            written: 0,
+        }
+    }
+
+    pub fn write(&mut self, report: &Report) -> io::Result<()> {
+        match self.format {
+            OutputFormat::Text => {
+                if self.written > 0 {
+                    writeln!(self.out)?;
+                }
+
+                writeln!(self.out, "{}", report)?;
+                self.written += 1;
+            }
+            OutputFormat::Json => {
+                for answer in &report.answers {
+                    let record = json!({
+                        "year": report.year,
+                        "day": report.day,
+                        "part": answer.part.number(),
+                        "answer": answer.answer,
+                        "duration": answer.duration.map(|duration| duration.as_nanos() as u64),
+                    });
+
+                    let separator = if self.written == 0 { "[" } else { "," };
+                    write!(self.out, "{}\n  {}", separator, record)?;
+                    self.written += 1;
+                }
+            }
+            OutputFormat::Csv => {
+                for answer in &report.answers {
+                    if self.written == 0 {
+                        writeln!(self.out, "{}", CSV_HEADER)?;
+                    }
+
+                    let duration = answer
+                        .duration
+                        .map(|duration| duration.as_nanos().to_string())
+                        .unwrap_or_default();
+
+                    writeln!(
+                        self.out,
+                        "{},{},{},{},{}",
+                        report.year,
+                        report.day,
+                        answer.part.number(),
+                        csv_field(&answer.answer),
+                        duration
+                    )?;
+                    self.written += 1;
+                }
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Closes the JSON array, or writes an empty array or lone CSV header if nothing was written.
+    pub fn finish(mut self) -> io::Result<()> {
+        if self.written == 0 {
+            match self.format {
+                OutputFormat::Text => (),
+                OutputFormat::Json => writeln!(self.out, "[]")?,
+                OutputFormat::Csv => writeln!(self.out, "{}", CSV_HEADER)?,
+            }
+        } else if self.format == OutputFormat::Json {
+            writeln!(self.out, "\n]")?;
+        }
+
+        self.out.flush()
+    }
+}
+
+fn csv_field(value: &str) -> String {
+    if value.contains([',', '"', '\n', '\r']) {
+        format!("\"{}\"", value.replace('"', "\"\""))
+    } else {
+        value.to_string()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn report(timed: bool) -> Report {
+        let duration = |micros| timed.then(|| Duration::from_micros(micros));
+
+        Report {
+            year: 2024,
+            day: 9,
+            parse: duration(15),
+            answers: vec![
+                Answer {
+                    part: Part::One,
+                    answer: "12".to_string(),
+                    duration: duration(1_250),
+                },
+                Answer {
+                    part: Part::Two,
+                    answer: "34".to_string(),
+                    duration: duration(40),
+                },
+            ],
+        }
+    }
+
+    #[test]
+    fn untimed() {
+        assert_eq!(
+            report(false).to_string(),
+            "=== 2024 Day 9 ===\n\nPart One:\n12\n\nPart Two:\n34"
+        );
+    }
+
+    #[test]
+    fn timed() {
+        assert_eq!(
+            report(true).to_string(),
+            "=== 2024 Day 9 ===\n\nParse (15.00µs)\n\nPart One (1.25ms):\n12\n\nPart Two (40.00µs):\n34"
+        );
+    }
+
+    fn write_all(format: OutputFormat, reports: &[Report]) -> String {
+        let mut output = Vec::new();
+        let mut writer = ReportWriter::new(&mut output, format);
+
+        for report in reports {
+            writer.write(report).unwrap();
+        }
+
+        writer.finish().unwrap();
+        String::from_utf8(output).unwrap()
+    }
+
+    #[test]
+    fn text_stream() {
+        let mut second = report(false);
+        second.day = 10;
+
+        assert_eq!(
+            write_all(OutputFormat::Text, &[report(false), second]),
+            "=== 2024 Day 9 ===\n\nPart One:\n12\n\nPart Two:\n34\n\n\
+             === 2024 Day 10 ===\n\nPart One:\n12\n\nPart Two:\n34\n"
+        );
+    }
+
+    #[test]
+    fn json() {
+        let output = write_all(OutputFormat::Json, &[report(true), report(false)]);
+        let records: serde_json::Value = serde_json::from_str(&output).unwrap();
+
+        assert_eq!(records.as_array().unwrap().len(), 4);
+        assert_eq!(
+            records[0],
+            json!({"year": 2024, "day": 9, "part": 1, "answer": "12", "duration": 1_250_000})
+        );
+        assert_eq!(records[3]["duration"], serde_json::Value::Null);
+    }
+
+    #[test]
+    fn csv() {
+        let mut report = report(true);
+        report.answers[1].answer = "a, \"b\"\nc".to_string();
+
+        assert_eq!(
+            write_all(OutputFormat::Csv, &[report]),
+            "year,day,part,answer,duration\n\
+             2024,9,1,12,1250000\n\
+             2024,9,2,\"a, \"\"b\"\"\nc\",40000\n"
+        );
+    }
+
+    #[test]
+    fn empty_output() {
+        assert_eq!(write_all(OutputFormat::Text, &[]), "");
+        assert_eq!(write_all(OutputFormat::Json, &[]), "[]\n");
+        assert_eq!(
+            write_all(OutputFormat::Csv, &[]),
+            "year,day,part,answer,duration\n"
+        );
+    }
+
+    #[test]
+    fn parse_format() {
+        assert_eq!("JSON".parse(), Ok(OutputFormat::Json));
+        assert_eq!("csv".parse(), Ok(OutputFormat::Csv));
+        assert!("yaml".parse::<OutputFormat>().is_err());
+    }
+
+    #[test]
+    fn single_part() {
+        let mut report = report(false);
+        report.answers.remove(0);
+
+        assert_eq!(report.to_string(), "=== 2024 Day 9 ===\n\nPart Two:\n34");
+    }
+}
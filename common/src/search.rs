@@ -0,0 +1,392 @@
+use std::{
+    cmp::Ordering,
+    collections::{hash_map::Entry, BinaryHeap, HashMap, HashSet, VecDeque},
+    hash::Hash,
+};
+
+use num_traits::Zero;
+
+/// Every node a search reached, with its cost and the node it was reached from.
+#[derive(Debug, Clone)]
+pub struct SearchTree<N, C> {
+    nodes: HashMap<N, (C, Option<N>)>,
+}
+
+impl<N: Clone + Eq + Hash, C: Copy> SearchTree<N, C> {
+    fn new(start: N, zero: C) -> Self {
+        Self {
+            nodes: HashMap::from([(start, (zero, None))]),
+        }
+    }
+
+    pub fn cost(&self, node: &N) -> Option<C> {
+        self.nodes.get(node).map(|(cost, _)| *cost)
+    }
+
+    pub fn contains(&self, node: &N) -> bool {
+        self.nodes.contains_key(node)
+    }
+
+    /// The nodes from the start to `node`, inclusive.
+    pub fn path_to(&self, node: &N) -> Option<Vec<N>> {
+        let mut path = vec![node.clone()];
+        let mut current = self.nodes.get(node)?;
+
+        while let (_, Some(parent)) = current {
+            path.push(parent.clone());
+            current = &self.nodes[parent];
+        }
+
+        path.reverse();
+        Some(path)
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = (&N, C)> {
+        self.nodes.iter().map(|(node, (cost, _))| (node, *cost))
+    }
+
+    pub fn nodes(&self) -> impl Iterator<Item = &N> {
+        self.nodes.keys()
+    }
+
+    pub fn len(&self) -> usize {
+        self.nodes.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.nodes.is_empty()
+    }
+}
+
+/// A heap entry ordered only by priority, lowest first, so nodes needn't be `Ord`.
+struct Queued<N, C> {
+    priority: C,
+    cost: C,
+    node: N,
+}
+
+impl<N, C: Ord> PartialEq for Queued<N, C> {
+    fn eq(&self, other: &Self) -> bool {
+        self.priority == other.priority
+    }
+}
+
+impl<N, C: Ord> Eq for Queued<N, C> {}
+
+impl<N, C: Ord> PartialOrd for Queued<N, C> {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl<N, C: Ord> Ord for Queued<N, C> {
+    fn cmp(&self, other: &Self) -> Ordering {
+        other.priority.cmp(&self.priority)
+    }
+}
+
+/// Breadth first from `start`, recording the number of steps to every reachable node.
+pub fn bfs<N, I>(start: N, mut successors: impl FnMut(&N) -> I) -> SearchTree<N, usize>
+where
+    N: Clone + Eq + Hash,
+    I: IntoIterator<Item = N>,
+{
+    let mut tree = SearchTree::new(start.clone(), 0);
+    let mut queue = VecDeque::from([start]);
+
+    while let Some(node) = queue.pop_front() {
+        let distance = tree.nodes[&node].0 + 1;
+
+        for next in successors(&node) {
+            if let Entry::Vacant(entry) = tree.nodes.entry(next.clone()) {
+                entry.insert((distance, Some(node.clone())));
+                queue.push_back(next);
+            }
+        }
+    }
+
+    tree
+}
+
+/// The shortest path from `start` to the first node satisfying `is_goal`, in steps.
+pub fn bfs_path<N, I>(
+    start: N,
+    mut successors: impl FnMut(&N) -> I,
+    mut is_goal: impl FnMut(&N) -> bool,
+) -> Option<(Vec<N>, usize)>
+where
+    N: Clone + Eq + Hash,
+    I: IntoIterator<Item = N>,
+{
+    let mut tree = SearchTree::new(start.clone(), 0);
+    let mut queue = VecDeque::from([start]);
+
+    while let Some(node) = queue.pop_front() {
+        let distance = tree.nodes[&node].0;
+
+        if is_goal(&node) {
+            return Some((tree.path_to(&node)?, distance));
+        }
+
+        for next in successors(&node) {
+            if let Entry::Vacant(entry) = tree.nodes.entry(next.clone()) {
+                entry.insert((distance + 1, Some(node.clone())));
+                queue.push_back(next);
+            }
+        }
+    }
+
+    None
+}
+
+/// Depth first from `start`, returning every reachable node in the order it was first visited.
+pub fn dfs<N, I>(start: N, mut successors: impl FnMut(&N) -> I) -> Vec<N>
+where
+    N: Clone + Eq + Hash,
+    I: IntoIterator<Item = N>,
+{
+    let mut visited = HashSet::new();
+    let mut order = Vec::new();
+    let mut stack = vec![start];
+
+    while let Some(node) = stack.pop() {
+        if !visited.insert(node.clone()) {
+            continue;
+        }
+
+        let mut next = successors(&node)
+            .into_iter()
+            .filter(|next| !visited.contains(next))
+            .collect::<Vec<_>>();
+
+        // Pushed in reverse so the first successor is explored first.
+        next.reverse();
+        stack.extend(next);
+        order.push(node);
+    }
+
+    order
+}
+
+/// Lowest total cost from `start` to every reachable node; `successors` yields `(node, edge cost)`.
+pub fn dijkstra<N, C, I>(start: N, mut successors: impl FnMut(&N) -> I) -> SearchTree<N, C>
+where
+    N: Clone + Eq + Hash,
+    C: Copy + Ord + Zero,
+    I: IntoIterator<Item = (N, C)>,
+{
+    let mut tree = SearchTree::new(start.clone(), C::zero());
+    let mut queue = BinaryHeap::from([Queued {
+        priority: C::zero(),
+        cost: C::zero(),
+        node: start,
+    }]);
+
+    while let Some(Queued { cost, node, .. }) = queue.pop() {
+        if cost > tree.nodes[&node].0 {
+            continue;
+        }
+
+        for (next, step) in successors(&node) {
+            let next_cost = cost + step;
+
+            let better = tree
+                .nodes
+                .get(&next)
+                .is_none_or(|(known, _)| next_cost < *known);
+
+            if better {
+                tree.nodes
+                    .insert(next.clone(), (next_cost, Some(node.clone())));
+                queue.push(Queued {
+                    priority: next_cost,
+                    cost: next_cost,
+                    node: next,
+                });
+            }
+        }
+    }
+
+    tree
+}
+
+/// The cheapest path from `start` to a goal, guided by a `heuristic` that never overestimates.
+pub fn astar<N, C, I>(
+    start: N,
+    mut successors: impl FnMut(&N) -> I,
+    mut heuristic: impl FnMut(&N) -> C,
+    mut is_goal: impl FnMut(&N) -> bool,
+) -> Option<(Vec<N>, C)>
+where
+    N: Clone + Eq + Hash,
+    C: Copy + Ord + Zero,
+    I: IntoIterator<Item = (N, C)>,
+{
+    let mut tree = SearchTree::new(start.clone(), C::zero());
+    let mut queue = BinaryHeap::from([Queued {
+        priority: heuristic(&start),
+        cost: C::zero(),
+        node: start,
+    }]);
+
+    while let Some(Queued { cost, node, .. }) = queue.pop() {
+        if is_goal(&node) {
+            return Some((tree.path_to(&node)?, cost));
+        }
+
+        if cost > tree.nodes[&node].0 {
+            continue;
+        }
+
+        for (next, step) in successors(&node) {
+            let next_cost = cost + step;
+
+            let better = tree
+                .nodes
+                .get(&next)
+                .is_none_or(|(known, _)| next_cost < *known);
+
+            if better {
+                tree.nodes
+                    .insert(next.clone(), (next_cost, Some(node.clone())));
+                queue.push(Queued {
+                    priority: next_cost + heuristic(&next),
+                    cost: next_cost,
+                    node: next,
+                });
+            }
+        }
+    }
+
+    None
+}
+
+/// Counts distinct paths from `start` to goal nodes; the graph must not have cycles.
+///
+/// Paths end at the first goal they reach.
+pub fn count_paths<N, I>(
+    start: N,
+    mut successors: impl FnMut(&N) -> I,
+    mut is_goal: impl FnMut(&N) -> bool,
+) -> u64
+where
+    N: Clone + Eq + Hash,
+    I: IntoIterator<Item = N>,
+{
+    fn count<N: Clone + Eq + Hash, I: IntoIterator<Item = N>>(
+        node: &N,
+        successors: &mut impl FnMut(&N) -> I,
+        is_goal: &mut impl FnMut(&N) -> bool,
+        counts: &mut HashMap<N, u64>,
+    ) -> u64 {
+        if is_goal(node) {
+            return 1;
+        }
+
+        if let Some(&known) = counts.get(node) {
+            return known;
+        }
+
+        let total = successors(node)
+            .into_iter()
+            .map(|next| count(&next, successors, is_goal, counts))
+            .sum();
+
+        counts.insert(node.clone(), total);
+        total
+    }
+
+    count(&start, &mut successors, &mut is_goal, &mut HashMap::new())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Edges of a small weighted graph:
+    ///
+    /// ```text
+    /// a -1-> b -1-> d
+    /// a -5-> c -1-> d
+    /// b -2-> c
+    /// ```
+    fn weighted(node: &char) -> Vec<(char, u32)> {
+        match node {
+            'a' => vec![('b', 1), ('c', 5)],
+            'b' => vec![('d', 1), ('c', 2)],
+            'c' => vec![('d', 1)],
+            _ => vec![],
+        }
+    }
+
+    fn unweighted(node: &char) -> Vec<char> {
+        weighted(node).into_iter().map(|(next, _)| next).collect()
+    }
+
+    /// Open cells of a 3x3 grid with the centre blocked.
+    fn ring((x, y): &(i32, i32)) -> Vec<(i32, i32)> {
+        [(0, -1), (1, 0), (0, 1), (-1, 0)]
+            .into_iter()
+            .map(|(offset_x, offset_y)| (x + offset_x, y + offset_y))
+            .filter(|&(x, y)| (0..3).contains(&x) && (0..3).contains(&y) && (x, y) != (1, 1))
+            .collect()
+    }
+
+    #[test]
+    fn bfs_distances() {
+        let tree = bfs('a', unweighted);
+
+        assert_eq!(tree.len(), 4);
+        assert_eq!(tree.cost(&'a'), Some(0));
+        assert_eq!(tree.cost(&'c'), Some(1));
+        assert_eq!(tree.cost(&'d'), Some(2));
+        assert_eq!(tree.path_to(&'d'), Some(vec!['a', 'b', 'd']));
+        assert_eq!(tree.path_to(&'z'), None);
+    }
+
+    #[test]
+    fn bfs_shortest_path() {
+        let (path, steps) = bfs_path((0, 0), ring, |&node| node == (2, 2)).unwrap();
+
+        assert_eq!(steps, 4);
+        assert_eq!(path.len(), 5);
+        assert_eq!(bfs_path((0, 0), ring, |&node| node == (1, 1)), None);
+    }
+
+    #[test]
+    fn dfs_order() {
+        assert_eq!(dfs('a', unweighted), ['a', 'b', 'd', 'c']);
+    }
+
+    #[test]
+    fn dijkstra_costs() {
+        let tree = dijkstra('a', weighted);
+
+        assert_eq!(tree.cost(&'c'), Some(3));
+        assert_eq!(tree.cost(&'d'), Some(2));
+        assert_eq!(tree.path_to(&'c'), Some(vec!['a', 'b', 'c']));
+    }
+
+    #[test]
+    fn astar_path() {
+        let manhattan = |&(x, y): &(i32, i32)| ((2 - x).abs() + (2 - y).abs()) as u32;
+        let successors = |node: &(i32, i32)| ring(node).into_iter().map(|next| (next, 1));
+
+        let (path, cost) = astar((0, 0), successors, manhattan, |&node| node == (2, 2)).unwrap();
+
+        assert_eq!(cost, 4);
+        assert_eq!(path.first(), Some(&(0, 0)));
+        assert_eq!(path.last(), Some(&(2, 2)));
+        assert_eq!(
+            astar('a', weighted, |_| 0, |&node| node == 'c'),
+            Some((vec!['a', 'b', 'c'], 3))
+        );
+    }
+
+    #[test]
+    fn paths() {
+        assert_eq!(count_paths('a', unweighted, |&node| node == 'd'), 3);
+        assert_eq!(count_paths('a', unweighted, |&node| node == 'z'), 0);
+        assert_eq!(count_paths('d', unweighted, |&node| node == 'd'), 1);
+    }
+}
@@ -0,0 +1,414 @@
+use std::{collections::BTreeMap, env, fmt::Display, io, process};
+
+use crate::{
+    image, timed,
+    viz::{self, Playback},
+    Answer, BenchReport, InputSource, OutputFormat, PuzzleError, PuzzleResult, Report,
+    ReportWriter, SolveError, Stats,
+};
+
+pub trait Solution {
+    const YEAR: u16;
+    const DAY: u8;
+
+    type Input;
+
+    fn parse(raw: &str) -> PuzzleResult<Self::Input>;
+
+    fn part_one(input: &Self::Input) -> PuzzleResult<impl Display>;
+
+    fn part_two(input: &Self::Input) -> PuzzleResult<impl Display>;
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum Part {
+    One,
+    Two,
+}
+
+impl Part {
+    pub const ALL: [Self; 2] = [Self::One, Self::Two];
+
+    pub fn from_number(value: u8) -> Option<Self> {
+        match value {
+            1 => Some(Self::One),
+            2 => Some(Self::Two),
+            _ => None,
+        }
+    }
+
+    pub fn number(self) -> u8 {
+        match self {
+            Self::One => 1,
+            Self::Two => 2,
+        }
+    }
+}
+
+impl Display for Part {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::One => write!(f, "One"),
+            Self::Two => write!(f, "Two"),
+        }
+    }
+}
+
+pub(crate) fn solve_part<S: Solution>(input: &S::Input, part: Part) -> PuzzleResult<String> {
+    match part {
+        Part::One => S::part_one(input).map(|answer| answer.to_string()),
+        Part::Two => S::part_two(input).map(|answer| answer.to_string()),
+    }
+}
+
+fn locate<S: Solution>(part: Option<Part>) -> impl FnOnce(PuzzleError) -> SolveError {
+    move |error| SolveError {
+        year: S::YEAR,
+        day: S::DAY,
+        part,
+        error,
+    }
+}
+
+fn solve_erased<S: Solution>(raw: &str, parts: &[Part]) -> Result<Report, SolveError> {
+    let (input, parse) = timed(|| S::parse(raw));
+    let input = input.map_err(locate::<S>(None))?;
+
+    let answers = parts
+        .iter()
+        .map(|&part| {
+            let (answer, duration) = timed(|| solve_part::<S>(&input, part));
+
+            Ok(Answer {
+                part,
+                answer: answer.map_err(locate::<S>(Some(part)))?,
+                duration: Some(duration),
+            })
+        })
+        .collect::<Result<_, _>>()?;
+
+    Ok(Report {
+        year: S::YEAR,
+        day: S::DAY,
+        parse: Some(parse),
+        answers,
+    })
+}
+
+fn bench_erased<S: Solution>(
+    raw: &str,
+    parts: &[Part],
+    iterations: u32,
+) -> Result<BenchReport, SolveError> {
+    let input = S::parse(raw).map_err(locate::<S>(None))?;
+    let parse = Stats::measure(iterations, || S::parse(raw));
+
+    let parts = parts
+        .iter()
+        .map(|&part| {
+            // Fail before timing rather than benchmarking an error.
+            solve_part::<S>(&input, part).map_err(locate::<S>(Some(part)))?;
+            let stats = Stats::measure(iterations, || solve_part::<S>(&input, part));
+
+            Ok((part, stats))
+        })
+        .collect::<Result<_, _>>()?;
+
+    Ok(BenchReport {
+        year: S::YEAR,
+        day: S::DAY,
+        iterations,
+        parse,
+        parts,
+    })
+}
+
+#[derive(Debug, Clone, Copy)]
+pub struct RegisteredSolution {
+    pub year: u16,
+    pub day: u8,
+    solve: fn(&str, &[Part]) -> Result<Report, SolveError>,
+    bench: fn(&str, &[Part], u32) -> Result<BenchReport, SolveError>,
+}
+
+impl RegisteredSolution {
+    fn new<S: Solution>() -> Self {
+        Self {
+            year: S::YEAR,
+            day: S::DAY,
+            solve: solve_erased::<S>,
+            bench: bench_erased::<S>,
+        }
+    }
+
+    pub fn solve(&self, raw: &str, parts: &[Part]) -> Result<Report, SolveError> {
+        (self.solve)(raw, parts)
+    }
+
+    pub fn bench(
+        &self,
+        raw: &str,
+        parts: &[Part],
+        iterations: u32,
+    ) -> Result<BenchReport, SolveError> {
+        (self.bench)(raw, parts, iterations)
+    }
+}
+
+#[derive(Debug, Default)]
+pub struct Registry {
+    solutions: BTreeMap<(u16, u8), RegisteredSolution>,
+}
+
+impl Registry {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn register<S: Solution>(&mut self) {
+        let previous = self
+            .solutions
+            .insert((S::YEAR, S::DAY), RegisteredSolution::new::<S>());
+
+        assert!(
+            previous.is_none(),
+            "{} Day {} registered twice",
+            S::YEAR,
+            S::DAY
+        );
+    }
+
+    pub fn get(&self, year: u16, day: u8) -> Option<&RegisteredSolution> {
+        self.solutions.get(&(year, day))
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = &RegisteredSolution> {
+        self.solutions.values()
+    }
+
+    pub fn year(&self, year: u16) -> impl Iterator<Item = &RegisteredSolution> {
+        self.solutions
+            .range((year, u8::MIN)..=(year, u8::MAX))
+            .map(|(_, solution)| solution)
+    }
+
+    pub fn years(&self) -> impl Iterator<Item = u16> + '_ {
+        let mut years = self.solutions.keys().map(|(year, _)| *year).peekable();
+
+        std::iter::from_fn(move || {
+            let year = years.next()?;
+            while years.next_if_eq(&year).is_some() {}
+            Some(year)
+        })
+    }
+
+    pub fn len(&self) -> usize {
+        self.solutions.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.solutions.is_empty()
+    }
+}
+
+/// Prints both parts in the format chosen by `$AOC_OUTPUT_FORMAT`, exiting on failure.
+pub fn solve<S: Solution>(raw: &str) {
+    match solve_erased::<S>(raw, &Part::ALL) {
+        Ok(report) => print_report(&report),
+        Err(error) => {
+            eprintln!("{}", error);
+            process::exit(1);
+        }
+    }
+}
+
+pub(crate) fn print_report(report: &Report) {
+    let format = OutputFormat::from_env().unwrap_or_else(|error| {
+        eprintln!("{}", error);
+        process::exit(1);
+    });
+
+    let mut writer = ReportWriter::new(io::stdout().lock(), format);
+    writer
+        .write(report)
+        .and_then(|_| writer.finish())
+        .expect("Failed to write report");
+}
+
+/// Loads the input named by the first argument (`-` for stdin) and prints both parts.
+///
+/// Simulations are drawn as they run when `$AOC_VISUALIZE` is set, and saved as images when
+/// `$AOC_EXPORT_DIR` is.
+pub fn solve_main<S: Solution>() {
+    match Playback::from_env() {
+        Ok(Some(playback)) => viz::enable(playback),
+        Ok(None) => (),
+        Err(error) => {
+            eprintln!("{}", error);
+            process::exit(1);
+        }
+    }
+
+    if let Some(directory) = image::export_dir_from_env() {
+        image::export_to(Some(image::day_directory(&directory, S::YEAR, S::DAY)));
+    }
+
+    let source = env::args()
+        .nth(1)
+        .map(|arg| InputSource::from_arg(&arg))
+        .unwrap_or_default();
+
+    match source.load(S::YEAR, S::DAY) {
+        Ok(raw) => solve::<S>(&raw),
+        Err(error) => {
+            eprintln!("{}", error);
+            process::exit(1);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::parse::{literal, parse_all, separated, unsigned};
+
+    struct Sum;
+
+    impl Solution for Sum {
+        const YEAR: u16 = 2000;
+        const DAY: u8 = 1;
+
+        type Input = Vec<u32>;
+
+        fn parse(raw: &str) -> PuzzleResult<Self::Input> {
+            Ok(parse_all(raw, separated(unsigned(), literal(" ")))?)
+        }
+
+        fn part_one(input: &Self::Input) -> PuzzleResult<impl Display> {
+            Ok(input.iter().sum::<u32>())
+        }
+
+        fn part_two(input: &Self::Input) -> PuzzleResult<impl Display> {
+            input
+                .iter()
+                .try_fold(1u32, |product, &value| product.checked_mul(value))
+                .ok_or_else(|| PuzzleError::no_solution("product overflowed"))
+        }
+    }
+
+    struct Later;
+
+    impl Solution for Later {
+        const YEAR: u16 = 2001;
+        const DAY: u8 = 3;
+
+        type Input = ();
+
+        fn parse(_raw: &str) -> PuzzleResult<Self::Input> {
+            Ok(())
+        }
+
+        fn part_one(_input: &Self::Input) -> PuzzleResult<impl Display> {
+            Ok("one")
+        }
+
+        fn part_two(_input: &Self::Input) -> PuzzleResult<impl Display> {
+            Ok("two")
+        }
+    }
+
+    #[test]
+    fn registry_solve() {
+        let mut registry = Registry::new();
+        registry.register::<Sum>();
+
+        let report = registry
+            .get(2000, 1)
+            .unwrap()
+            .solve("2 3 4", &Part::ALL)
+            .unwrap();
+
+        assert_eq!((report.year, report.day), (2000, 1));
+        assert_eq!(report.answers[0].answer, "9");
+        assert_eq!(report.answers[1].answer, "24");
+    }
+
+    #[test]
+    fn registry_solve_part() {
+        let mut registry = Registry::new();
+        registry.register::<Sum>();
+
+        let report = registry
+            .get(2000, 1)
+            .unwrap()
+            .solve("2 3 4", &[Part::Two])
+            .unwrap();
+
+        assert_eq!(report.answers.len(), 1);
+        assert_eq!(report.answers[0].part, Part::Two);
+        assert_eq!(report.answers[0].answer, "24");
+    }
+
+    #[test]
+    fn registry_bench() {
+        let mut registry = Registry::new();
+        registry.register::<Sum>();
+
+        let report = registry
+            .get(2000, 1)
+            .unwrap()
+            .bench("2 3 4", &Part::ALL, 5)
+            .unwrap();
+
+        assert_eq!(report.iterations, 5);
+        assert_eq!(report.parts.len(), 2);
+        assert!(report.parse.min <= report.parse.median);
+    }
+
+    #[test]
+    fn registry_errors() {
+        let mut registry = Registry::new();
+        registry.register::<Sum>();
+        let solution = registry.get(2000, 1).unwrap();
+
+        let error = solution.solve("2 x 4", &Part::ALL).unwrap_err();
+
+        assert_eq!(error.part, None);
+        assert_eq!(
+            error.to_string(),
+            "2000 Day 1 parse: Invalid input at line 1, column 3: expected a digit, found 'x'"
+        );
+
+        let error = solution.solve("65536 65536", &Part::ALL).unwrap_err();
+
+        assert_eq!(error.part, Some(Part::Two));
+        assert_eq!(error.error, PuzzleError::no_solution("product overflowed"));
+        assert!(solution.solve("65536 65536", &[Part::One]).is_ok());
+        assert!(solution.bench("65536 65536", &Part::ALL, 1).is_err());
+    }
+
+    #[test]
+    fn registry_enumerate() {
+        let mut registry = Registry::new();
+        registry.register::<Later>();
+        registry.register::<Sum>();
+
+        let days = registry
+            .iter()
+            .map(|solution| (solution.year, solution.day))
+            .collect::<Vec<_>>();
+
+        assert_eq!(days, [(2000, 1), (2001, 3)]);
+        assert_eq!(registry.years().collect::<Vec<_>>(), [2000, 2001]);
+        assert_eq!(registry.year(2001).count(), 1);
+        assert!(registry.get(2001, 1).is_none());
+    }
+
+    #[test]
+    #[should_panic]
+    fn registry_duplicate() {
+        let mut registry = Registry::new();
+        registry.register::<Sum>();
+        registry.register::<Sum>();
+    }
+}
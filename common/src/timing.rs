@@ -0,0 +1,118 @@
+use std::{
+    fmt::Display,
+    hint::black_box,
+    time::{Duration, Instant},
+};
+
+use crate::Part;
+
+pub fn timed<T>(f: impl FnOnce() -> T) -> (T, Duration) {
+    let start = Instant::now();
+    let value = f();
+    (value, start.elapsed())
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Stats {
+    pub min: Duration,
+    pub median: Duration,
+    pub mean: Duration,
+}
+
+impl Stats {
+    pub fn from_samples(mut samples: Vec<Duration>) -> Self {
+        assert!(!samples.is_empty(), "No samples to summarize");
+
+        samples.sort();
+
+        let middle = samples.len() / 2;
+        let median = if samples.len().is_multiple_of(2) {
+            (samples[middle - 1] + samples[middle]) / 2
+        } else {
+            samples[middle]
+        };
+
+        Self {
+            min: samples[0],
+            median,
+            mean: samples.iter().sum::<Duration>() / samples.len() as u32,
+        }
+    }
+
+    pub fn measure<T>(iterations: u32, mut f: impl FnMut() -> T) -> Self {
+        let samples = (0..iterations.max(1))
+            .map(|_| timed(|| black_box(f())).1)
+            .collect();
+
+        Self::from_samples(samples)
+    }
+}
+
+impl Display for Stats {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "min {:.2?}, median {:.2?}, mean {:.2?}",
+            self.min, self.median, self.mean
+        )
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct BenchReport {
+    pub year: u16,
+    pub day: u8,
+    pub iterations: u32,
+    pub parse: Stats,
+    pub parts: Vec<(Part, Stats)>,
+}
+
+impl Display for BenchReport {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "=== {} Day {} === ({} iterations)\n\nParse:\n{}",
+            self.year, self.day, self.iterations, self.parse
+        )?;
+
+        for (part, stats) in &self.parts {
+            write!(f, "\n\nPart {}:\n{}", part, stats)?;
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn millis(values: &[u64]) -> Vec<Duration> {
+        values.iter().copied().map(Duration::from_millis).collect()
+    }
+
+    #[test]
+    fn stats_odd() {
+        let stats = Stats::from_samples(millis(&[5, 1, 3]));
+
+        assert_eq!(stats.min, Duration::from_millis(1));
+        assert_eq!(stats.median, Duration::from_millis(3));
+        assert_eq!(stats.mean, Duration::from_millis(3));
+    }
+
+    #[test]
+    fn stats_even() {
+        let stats = Stats::from_samples(millis(&[8, 2, 4, 2]));
+
+        assert_eq!(stats.min, Duration::from_millis(2));
+        assert_eq!(stats.median, Duration::from_millis(3));
+        assert_eq!(stats.mean, Duration::from_millis(4));
+    }
+
+    #[test]
+    fn measure_iterations() {
+        let mut calls = 0;
+        Stats::measure(7, || calls += 1);
+        assert_eq!(calls, 7);
+    }
+}
@@ -0,0 +1,373 @@
+//! Draws simulations to the terminal, one [`Frame`] at a time.
+//!
+//! Solutions call [`show`] wherever there's something worth seeing; it does nothing until
+//! [`enable`] is called, which the runner does for `--visualize`.
+
+use std::{
+    env,
+    error::Error,
+    fmt::Display,
+    io::{self, Write},
+    str::FromStr,
+    sync::Mutex,
+    thread,
+    time::{Duration, Instant},
+};
+
+use crate::Grid;
+
+/// Turns on visualization for `solve_main`, either `step` or a frame rate.
+pub const VISUALIZE_VAR: &str = "AOC_VISUALIZE";
+
+const RESET: &str = "\x1b[0m";
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Colour {
+    Red,
+    Green,
+    Yellow,
+    Blue,
+    Magenta,
+    Cyan,
+    White,
+    Grey,
+}
+
+impl Colour {
+    /// The SGR code setting this as the foreground.
+    fn code(self) -> u8 {
+        match self {
+            Self::Red => 31,
+            Self::Green => 32,
+            Self::Yellow => 33,
+            Self::Blue => 34,
+            Self::Magenta => 35,
+            Self::Cyan => 36,
+            Self::White => 97,
+            Self::Grey => 90,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Cell {
+    pub character: char,
+    pub colour: Option<Colour>,
+}
+
+impl Cell {
+    pub const EMPTY: Self = Self::plain(' ');
+
+    pub const fn plain(character: char) -> Self {
+        Self {
+            character,
+            colour: None,
+        }
+    }
+
+    pub const fn new(character: char, colour: Colour) -> Self {
+        Self {
+            character,
+            colour: Some(colour),
+        }
+    }
+}
+
+/// A snapshot of a simulation, with an optional caption drawn underneath.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Frame {
+    cells: Grid<Cell>,
+    caption: Option<String>,
+}
+
+impl Frame {
+    pub fn new(width: usize, height: usize) -> Self {
+        Self {
+            cells: Grid::filled(width, height, Cell::EMPTY),
+            caption: None,
+        }
+    }
+
+    pub fn from_grid<T>(grid: &Grid<T>, mut f: impl FnMut(&T) -> Cell) -> Self {
+        let cells = grid.iter().map(|(_, cell)| f(cell)).collect();
+
+        Self {
+            cells: Grid::new(grid.width(), grid.height(), cells),
+            caption: None,
+        }
+    }
+
+    pub fn with_caption(mut self, caption: impl Into<String>) -> Self {
+        self.caption = Some(caption.into());
+        self
+    }
+
+    pub fn width(&self) -> usize {
+        self.cells.width()
+    }
+
+    pub fn height(&self) -> usize {
+        self.cells.height()
+    }
+
+    pub fn get(&self, position: impl Into<(usize, usize)>) -> Option<&Cell> {
+        self.cells.get(position)
+    }
+
+    /// Draws `cell` at `position`, ignoring positions outside the frame.
+    pub fn set(&mut self, position: impl Into<(usize, usize)>, cell: Cell) {
+        if let Some(current) = self.cells.get_mut(position) {
+            *current = cell;
+        }
+    }
+
+    /// The characters without any colours, one line per row.
+    pub fn plain(&self) -> String {
+        self.cells
+            .rows()
+            .map(|row| row.iter().map(|cell| cell.character).collect::<String>())
+            .collect::<Vec<_>>()
+            .join("\n")
+    }
+}
+
+impl Display for Frame {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        for (y, row) in self.cells.rows().enumerate() {
+            if y > 0 {
+                writeln!(f)?;
+            }
+
+            let mut colour = None;
+
+            for cell in row {
+                if cell.colour != colour {
+                    match cell.colour {
+                        Some(next) => write!(f, "\x1b[{}m", next.code())?,
+                        None => write!(f, "{}", RESET)?,
+                    }
+
+                    colour = cell.colour;
+                }
+
+                write!(f, "{}", cell.character)?;
+            }
+
+            if colour.is_some() {
+                write!(f, "{}", RESET)?;
+            }
+        }
+
+        if let Some(caption) = &self.caption {
+            write!(f, "\n{}", caption)?;
+        }
+
+        Ok(())
+    }
+}
+
+/// How to move from one frame to the next.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Playback {
+    /// Waits for enter after each frame.
+    Step,
+    /// Waits this long between frames.
+    Animate(Duration),
+}
+
+impl Playback {
+    /// One frame every `1 / fps` seconds, or as fast as possible for 0.
+    pub fn animate(fps: u32) -> Self {
+        match fps {
+            0 => Self::Animate(Duration::ZERO),
+            fps => Self::Animate(Duration::from_secs(1) / fps),
+        }
+    }
+
+    /// Reads `$AOC_VISUALIZE`, which is unset when visualization is off.
+    pub fn from_env() -> Result<Option<Self>, UnknownPlayback> {
+        env::var(VISUALIZE_VAR)
+            .ok()
+            .map(|value| value.parse())
+            .transpose()
+    }
+}
+
+impl FromStr for Playback {
+    type Err = UnknownPlayback;
+
+    /// `step`, or a number of frames per second.
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        if s.eq_ignore_ascii_case("step") {
+            return Ok(Self::Step);
+        }
+
+        s.parse()
+            .map(Self::animate)
+            .map_err(|_| UnknownPlayback(s.to_string()))
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct UnknownPlayback(pub String);
+
+impl Display for UnknownPlayback {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "Unknown playback '{}', expected step or a frame rate",
+            self.0
+        )
+    }
+}
+
+impl Error for UnknownPlayback {}
+
+/// Draws frames over each other on a terminal.
+#[derive(Debug)]
+pub struct Visualizer<W> {
+    writer: W,
+    playback: Playback,
+    frames: usize,
+    last: Option<Instant>,
+}
+
+impl<W: Write> Visualizer<W> {
+    pub fn new(writer: W, playback: Playback) -> Self {
+        Self {
+            writer,
+            playback,
+            frames: 0,
+            last: None,
+        }
+    }
+
+    pub fn frames(&self) -> usize {
+        self.frames
+    }
+
+    pub fn show(&mut self, frame: &Frame) -> io::Result<()> {
+        if let Playback::Animate(delay) = self.playback {
+            // Building the frame counts towards the delay, so slow simulations don't lag further.
+            if let Some(elapsed) = self.last.map(|last| last.elapsed()) {
+                thread::sleep(delay.saturating_sub(elapsed));
+            }
+        }
+
+        self.frames += 1;
+
+        // Clear once, then draw over the top to avoid flickering.
+        if self.frames == 1 {
+            write!(self.writer, "\x1b[2J")?;
+        }
+
+        write!(self.writer, "\x1b[H{}\x1b[J", frame)?;
+
+        match self.playback {
+            Playback::Step => {
+                write!(self.writer, "\nFrame {}, press enter", self.frames)?;
+                self.writer.flush()?;
+                io::stdin().read_line(&mut String::new())?;
+            }
+            Playback::Animate(_) => {
+                writeln!(self.writer)?;
+                self.writer.flush()?;
+            }
+        }
+
+        self.last = Some(Instant::now());
+        Ok(())
+    }
+}
+
+static VISUALIZER: Mutex<Option<Visualizer<io::Stderr>>> = Mutex::new(None);
+
+/// Starts drawing frames passed to [`show`] to stderr, leaving stdout for answers.
+pub fn enable(playback: Playback) {
+    *lock() = Some(Visualizer::new(io::stderr(), playback));
+}
+
+pub fn disable() {
+    *lock() = None;
+}
+
+pub fn is_enabled() -> bool {
+    lock().is_some()
+}
+
+/// Draws the frame built by `frame` when visualization is on; otherwise `frame` isn't called,
+/// so this costs next to nothing left in a solution.
+pub fn show(frame: impl FnOnce() -> Frame) {
+    if let Some(visualizer) = lock().as_mut() {
+        visualizer.show(&frame()).expect("Failed to draw frame");
+    }
+}
+
+fn lock() -> std::sync::MutexGuard<'static, Option<Visualizer<io::Stderr>>> {
+    VISUALIZER
+        .lock()
+        .unwrap_or_else(|poisoned| poisoned.into_inner())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn frame() -> Frame {
+        let grid = Grid::from_str_with("#.\n.#", |character| character == '#').unwrap();
+
+        Frame::from_grid(&grid, |&wall| {
+            if wall {
+                Cell::new('#', Colour::Grey)
+            } else {
+                Cell::plain('.')
+            }
+        })
+    }
+
+    #[test]
+    fn render() {
+        let mut frame = frame();
+        frame.set((1, 0), Cell::new('@', Colour::Red));
+        frame.set((5, 5), Cell::plain('!'));
+
+        assert_eq!(frame.plain(), "#@\n.#");
+        assert_eq!(
+            frame.with_caption("Step 1").to_string(),
+            "\x1b[90m#\x1b[31m@\x1b[0m\n.\x1b[90m#\x1b[0m\nStep 1"
+        );
+    }
+
+    #[test]
+    fn playback() {
+        assert_eq!("step".parse(), Ok(Playback::Step));
+        assert_eq!(
+            "4".parse(),
+            Ok(Playback::Animate(Duration::from_millis(250)))
+        );
+        assert_eq!("0".parse(), Ok(Playback::Animate(Duration::ZERO)));
+        assert_eq!(
+            "fast".parse::<Playback>(),
+            Err(UnknownPlayback("fast".to_string()))
+        );
+    }
+
+    #[test]
+    fn visualizer() {
+        let mut output = Vec::new();
+        let mut visualizer = Visualizer::new(&mut output, Playback::animate(0));
+
+        visualizer.show(&frame()).unwrap();
+        visualizer.show(&Frame::new(1, 1)).unwrap();
+        assert_eq!(visualizer.frames(), 2);
+
+        let output = String::from_utf8(output).unwrap();
+        assert!(output.starts_with("\x1b[2J\x1b[H"));
+        assert!(output.ends_with("\x1b[H \x1b[J\n"));
+    }
+
+    #[test]
+    fn disabled() {
+        show(|| unreachable!("Frames shouldn't be built when disabled"));
+    }
+}
@@ -0,0 +1,98 @@
+use std::fmt::Display;
+
+use common::*;
+
+fn parse_input(raw: &str) -> PuzzleResult<String> {
+    let instructions = raw.trim_end();
+
+    match instructions
+        .char_indices()
+        .find(|(_, value)| !matches!(value, '(' | ')'))
+    {
+        Some((column, value)) => Err(PuzzleError::parse(
+            1,
+            column + 1,
+            format!("expected '(' or ')', found {:?}", value),
+        )),
+        None => Ok(instructions.to_string()),
+    }
+}
+
+fn step(value: char, floor: &mut i32) {
+    match value {
+        ')' => *floor -= 1,
+        '(' => *floor += 1,
+        _ => (),
+    }
+}
+
+fn part_one(input: &str) -> i32 {
+    let mut floor = 0;
+
+    input.chars().for_each(|value| step(value, &mut floor));
+
+    floor
+}
+
+fn part_two(input: &str) -> PuzzleResult<usize> {
+    let mut floor = 0;
+
+    for (i, value) in input.chars().enumerate() {
+        step(value, &mut floor);
+
+        if floor == -1 {
+            return Ok(i + 1);
+        }
+    }
+
+    Err(PuzzleError::no_solution(format!(
+        "never entered the basement; ended on floor {}",
+        floor
+    )))
+}
+
+pub struct Day1;
+
+impl Solution for Day1 {
+    const YEAR: u16 = 2015;
+    const DAY: u8 = 1;
+
+    type Input = String;
+
+    fn parse(raw: &str) -> PuzzleResult<Self::Input> {
+        parse_input(raw)
+    }
+
+    fn part_one(input: &Self::Input) -> PuzzleResult<impl Display> {
+        Ok(part_one(input))
+    }
+
+    fn part_two(input: &Self::Input) -> PuzzleResult<impl Display> {
+        part_two(input)
+    }
+}
+
+pub fn register(registry: &mut Registry) {
+    registry.register::<Day1>();
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn basement() {
+        assert_eq!(part_two(")"), Ok(1));
+        assert_eq!(part_two("()())"), Ok(5));
+        assert!(part_two("((").is_err());
+    }
+
+    #[test]
+    fn parse_error() {
+        let error = Day1::parse("(()x)").unwrap_err();
+        assert_eq!(
+            error,
+            PuzzleError::parse(1, 4, "expected '(' or ')', found 'x'")
+        );
+    }
+}
@@ -0,0 +1,212 @@
+#![feature(iter_array_chunks)]
+
+use std::fmt::Display;
+
+use common::*;
+
+fn char_bitmask(value: char) -> u64 {
+    let place = match value {
+        'A'..='Z' => 26 + value as u8 - b'A',
+        'a'..='z' => value as u8 - b'a',
+        _ => panic!("Char unsupported: {}", value),
+    } + 1;
+
+    1 << place
+}
+
+fn string_bitmask(value: &str) -> u64 {
+    let mut output = 0;
+
+    value
+        .chars()
+        .map(char_bitmask)
+        .for_each(|bitmask| output |= bitmask);
+
+    output
+}
+
+/// The priority of the single item in `value`, or `None` if it's empty.
+fn bitmask_inverse(value: u64) -> Option<u8> {
+    value.checked_ilog2().map(|place| place as u8)
+}
+
+fn split_half(value: &str) -> (&str, &str) {
+    value.split_at(value.len() / 2)
+}
+
+fn parse_input(raw: &str) -> PuzzleResult<Vec<String>> {
+    let sacks = raw
+        .lines()
+        .enumerate()
+        .map(|(index, sack)| {
+            if let Some((column, item)) = sack
+                .char_indices()
+                .find(|(_, item)| !item.is_ascii_alphabetic())
+            {
+                return Err(PuzzleError::parse(
+                    index + 1,
+                    column + 1,
+                    format!("expected an item, found {:?}", item),
+                ));
+            }
+
+            // Both compartments hold the same number of items.
+            if sack.len() % 2 != 0 {
+                return Err(PuzzleError::parse(
+                    index + 1,
+                    sack.len() + 1,
+                    "a sack needs an even number of items",
+                ));
+            }
+
+            Ok(sack.to_string())
+        })
+        .collect::<PuzzleResult<Vec<_>>>()?;
+
+    // Elves are grouped in threes.
+    if sacks.len() % 3 != 0 {
+        return Err(PuzzleError::parse(
+            sacks.len() + 1,
+            1,
+            "expected a sack to finish the group of three",
+        ));
+    }
+
+    Ok(sacks)
+}
+
+fn part_one(sacks: &[String]) -> PuzzleResult<u32> {
+    sacks
+        .iter()
+        .map(|sack| split_half(sack))
+        .map(|(left, right)| string_bitmask(left) & string_bitmask(right))
+        .map(|bitmask| bitmask_inverse(bitmask).map(u32::from))
+        .sum::<Option<u32>>()
+        .ok_or_else(|| PuzzleError::no_solution("a sack's compartments share no item"))
+}
+
+fn part_two(sacks: &[String]) -> PuzzleResult<u32> {
+    sacks
+        .iter()
+        .array_chunks::<3>()
+        .map(|[a, b, c]| string_bitmask(a) & string_bitmask(b) & string_bitmask(c))
+        .map(|bitmask| bitmask_inverse(bitmask).map(u32::from))
+        .sum::<Option<u32>>()
+        .ok_or_else(|| PuzzleError::no_solution("a group's sacks share no badge"))
+}
+
+pub struct Day3;
+
+impl Solution for Day3 {
+    const YEAR: u16 = 2022;
+    const DAY: u8 = 3;
+
+    type Input = Vec<String>;
+
+    fn parse(raw: &str) -> PuzzleResult<Self::Input> {
+        parse_input(raw)
+    }
+
+    fn part_one(input: &Self::Input) -> PuzzleResult<impl Display> {
+        part_one(input)
+    }
+
+    fn part_two(input: &Self::Input) -> PuzzleResult<impl Display> {
+        part_two(input)
+    }
+}
+
+pub fn register(registry: &mut Registry) {
+    registry.register::<Day3>();
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE_ONE: &str = "vJrwpWtwJgWrhcsFMMfFFhFp
+jqHRNqRjqzjGDLGLrsFMfFZSrLrFZsSL
+PmmdzqPrVvPwwTWBwg
+wMqvLMZHhHMvwLHjbvcjnnSBnvTQFn
+ttgJtRGJQctTZtZT
+CrZsJsPPZsGzwwsLwLmpwMDw";
+
+    const EXAMPLE_TWO: &str = "vJrwpWtwJgWrhcsFMMfFFhFp
+jqHRNqRjqzjGDLGLrsFMfFZSrLrFZsSL
+PmmdzqPrVvPwwTWBwg";
+
+    const EXAMPLE_THREE: &str = "wMqvLMZHhHMvwLHjbvcjnnSBnvTQFn
+ttgJtRGJQctTZtZT
+CrZsJsPPZsGzwwsLwLmpwMDw";
+
+    #[test]
+    fn split() {
+        let (left, right) = split_half("vJrwpWtwJgWrhcsFMMfFFhFp");
+        assert_eq!(left, "vJrwpWtwJgWr");
+        assert_eq!(right, "hcsFMMfFFhFp");
+    }
+
+    #[test]
+    fn bitmask_inverse_a() {
+        let bitmask = char_bitmask('a');
+        assert_eq!(bitmask_inverse(bitmask), Some(1));
+    }
+
+    #[test]
+    fn bitmask_inverse_b() {
+        let bitmask = char_bitmask('b');
+        assert_eq!(bitmask_inverse(bitmask), Some(2));
+    }
+
+    #[test]
+    fn bitmask_inverse_z() {
+        let bitmask = char_bitmask('Z');
+        assert_eq!(bitmask_inverse(bitmask), Some(52));
+    }
+
+    #[test]
+    fn example_1() {
+        let sacks = parse_input(EXAMPLE_ONE).unwrap();
+        assert_eq!(part_one(&sacks), Ok(157));
+    }
+
+    #[test]
+    fn example_2() {
+        let sacks = parse_input(EXAMPLE_TWO).unwrap();
+        assert_eq!(part_two(&sacks), Ok(18));
+    }
+
+    #[test]
+    fn example_3() {
+        let sacks = parse_input(EXAMPLE_THREE).unwrap();
+        assert_eq!(part_two(&sacks), Ok(52));
+    }
+
+    #[test]
+    fn parse_error() {
+        let error = Day3::parse("vJrwpWtwJgWr\nhcsF1MfFFhFp\nPmmdzq").unwrap_err();
+        assert_eq!(
+            error,
+            PuzzleError::parse(2, 5, "expected an item, found '1'")
+        );
+
+        let error = Day3::parse("vJrwpWtwJgWr\nhcsFM\nPmmdzq").unwrap_err();
+        assert_eq!(
+            error,
+            PuzzleError::parse(2, 6, "a sack needs an even number of items")
+        );
+
+        let error = Day3::parse("vJrwpWtwJgWr\nhcsFMMfFFhFp\nPmmdzq\nPmmdzq").unwrap_err();
+        assert_eq!(
+            error,
+            PuzzleError::parse(5, 1, "expected a sack to finish the group of three")
+        );
+    }
+
+    #[test]
+    fn nothing_shared() {
+        let sacks = parse_input("AB\nab\nAa").unwrap();
+        assert!(part_one(&sacks).is_err());
+        assert!(part_two(&sacks).is_err());
+    }
+}
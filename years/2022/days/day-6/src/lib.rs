@@ -0,0 +1,186 @@
+use std::fmt::Display;
+
+use common::*;
+
+fn letter_mask(letter: char) -> u32 {
+    let number = letter as u8 - b'a';
+    1 << number
+}
+
+fn parse_input(raw: &str) -> PuzzleResult<Vec<String>> {
+    raw.lines()
+        .enumerate()
+        .map(|(index, data)| {
+            match data
+                .char_indices()
+                .find(|(_, letter)| !letter.is_ascii_lowercase())
+            {
+                Some((column, letter)) => Err(PuzzleError::parse(
+                    index + 1,
+                    column + 1,
+                    format!("expected a lowercase letter, found {:?}", letter),
+                )),
+                None => Ok(data.to_string()),
+            }
+        })
+        .collect()
+}
+
+/// How many characters have been read once the last `length` are all different, or `None` if
+/// that never happens.
+fn find_marker(data: &str, length: usize) -> Option<usize> {
+    let last_start = data.len().checked_sub(length)?;
+    let (marker_index, _) = (0..=last_start).enumerate().find(|(_, start_index)| {
+        let mut final_mask = 0;
+
+        data[*start_index..*start_index + length]
+            .chars()
+            .map(letter_mask)
+            .for_each(|mask| final_mask |= mask);
+
+        final_mask.count_ones() as usize == length
+    })?;
+
+    Some(marker_index + length)
+}
+
+fn find_marker_short(data: &str) -> Option<usize> {
+    find_marker(data, 4)
+}
+
+fn find_marker_long(data: &str) -> Option<usize> {
+    find_marker(data, 14)
+}
+
+fn part_one(data: &[String]) -> PuzzleResult<usize> {
+    data.iter()
+        .map(|line| find_marker_short(line))
+        .sum::<Option<usize>>()
+        .ok_or_else(|| PuzzleError::no_solution("a datastream has no start-of-packet marker"))
+}
+
+fn part_two(data: &[String]) -> PuzzleResult<usize> {
+    data.iter()
+        .map(|line| find_marker_long(line))
+        .sum::<Option<usize>>()
+        .ok_or_else(|| PuzzleError::no_solution("a datastream has no start-of-message marker"))
+}
+
+pub struct Day6;
+
+impl Solution for Day6 {
+    const YEAR: u16 = 2022;
+    const DAY: u8 = 6;
+
+    type Input = Vec<String>;
+
+    fn parse(raw: &str) -> PuzzleResult<Self::Input> {
+        parse_input(raw)
+    }
+
+    fn part_one(input: &Self::Input) -> PuzzleResult<impl Display> {
+        part_one(input)
+    }
+
+    fn part_two(input: &Self::Input) -> PuzzleResult<impl Display> {
+        part_two(input)
+    }
+}
+
+pub fn register(registry: &mut Registry) {
+    registry.register::<Day6>();
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn letter_mask_a() {
+        assert_eq!(letter_mask('a'), 1);
+    }
+
+    #[test]
+    fn letter_mask_b() {
+        assert_eq!(letter_mask('b'), 2);
+    }
+
+    #[test]
+    fn letter_mask_z() {
+        assert_eq!(letter_mask('z'), 1 << 25);
+    }
+
+    #[test]
+    fn example_1_0() {
+        assert_eq!(find_marker_short("bvwbjplbgvbhsrlpgdmjqwftvncz"), Some(5));
+    }
+
+    #[test]
+    fn example_1_1() {
+        assert_eq!(find_marker_short("nppdvjthqldpwncqszvftbrmjlhg"), Some(6));
+    }
+
+    #[test]
+    fn example_1_2() {
+        assert_eq!(
+            find_marker_short("nznrnfrfntjfmvfwmzdfjlvtqnbhcprsg"),
+            Some(10)
+        );
+    }
+
+    #[test]
+    fn example_1_3() {
+        assert_eq!(
+            find_marker_short("zcfzfwzzqfrljwzlrfnpqdbhtmscgvjw"),
+            Some(11)
+        );
+    }
+
+    #[test]
+    fn example_2_0() {
+        assert_eq!(find_marker_long("mjqjpqmgbljsphdztnvjfqwrcgsmlb"), Some(19));
+    }
+
+    #[test]
+    fn example_2_1() {
+        assert_eq!(find_marker_long("bvwbjplbgvbhsrlpgdmjqwftvncz"), Some(23));
+    }
+
+    #[test]
+    fn example_2_2() {
+        assert_eq!(find_marker_long("nppdvjthqldpwncqszvftbrmjlhg"), Some(23));
+    }
+
+    #[test]
+    fn example_2_3() {
+        assert_eq!(
+            find_marker_long("nznrnfrfntjfmvfwmzdfjlvtqnbhcprsg"),
+            Some(29)
+        );
+    }
+
+    #[test]
+    fn example_2_4() {
+        assert_eq!(
+            find_marker_long("zcfzfwzzqfrljwzlrfnpqdbhtmscgvjw"),
+            Some(26)
+        );
+    }
+
+    #[test]
+    fn no_marker() {
+        assert_eq!(find_marker_short("abab"), None);
+        assert_eq!(find_marker_short("abc"), None);
+        assert_eq!(find_marker_short("abcd"), Some(4));
+    }
+
+    #[test]
+    fn parse_error() {
+        let error =
+            Day6::parse("bvwbjplbgvbhsrlpgdmjqwftvncz\nnppdVjthqldpwncqszvftbrmjlhg").unwrap_err();
+        assert_eq!(
+            error,
+            PuzzleError::parse(2, 5, "expected a lowercase letter, found 'V'")
+        );
+    }
+}
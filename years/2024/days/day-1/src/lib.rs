@@ -0,0 +1,148 @@
+use std::{collections::HashMap, fmt::Display};
+
+use common::{parse::*, *};
+
+fn split_data(data: &str) -> Result<(Vec<u32>, Vec<u32>), ParseError> {
+    let pairs: Vec<(u32, u32)> = parse_all(
+        data,
+        lines(separated_pair(unsigned(), spaces(), unsigned())),
+    )?;
+
+    Ok(pairs.into_iter().unzip())
+}
+
+fn distance(left: Vec<u32>, right: Vec<u32>) -> u32 {
+    left.into_iter()
+        .zip(right)
+        .map(|(left_value, right_value)| left_value.abs_diff(right_value))
+        .sum()
+}
+
+#[derive(Debug, Default)]
+struct Similarity {
+    sum: u32,
+    occurrences: u32,
+}
+
+impl Similarity {
+    fn score(&self) -> u32 {
+        self.sum * self.occurrences
+    }
+}
+
+fn similarity_sum(similarity_map: &mut HashMap<u32, Similarity>, left: Vec<u32>) {
+    left.into_iter()
+        .for_each(|value| similarity_map.entry(value).or_default().sum += value);
+}
+
+fn similarity_occurrences(similarity_map: &mut HashMap<u32, Similarity>, right: Vec<u32>) {
+    right
+        .into_iter()
+        .for_each(|value| similarity_map.entry(value).or_default().occurrences += 1);
+}
+
+fn calculate_similarity(similarity_map: HashMap<u32, Similarity>) -> u32 {
+    similarity_map.values().map(Similarity::score).sum()
+}
+
+fn part_one(mut left: Vec<u32>, mut right: Vec<u32>) -> u32 {
+    left.sort();
+    right.sort();
+
+    distance(left, right)
+}
+
+fn part_two(left: Vec<u32>, right: Vec<u32>) -> u32 {
+    let mut similarity_map = HashMap::<u32, Similarity>::new();
+
+    similarity_sum(&mut similarity_map, left);
+    similarity_occurrences(&mut similarity_map, right);
+
+    calculate_similarity(similarity_map)
+}
+
+pub struct Day1;
+
+impl Solution for Day1 {
+    const YEAR: u16 = 2024;
+    const DAY: u8 = 1;
+
+    type Input = (Vec<u32>, Vec<u32>);
+
+    fn parse(raw: &str) -> PuzzleResult<Self::Input> {
+        Ok(split_data(raw)?)
+    }
+
+    fn part_one(input: &Self::Input) -> PuzzleResult<impl Display> {
+        let (left, right) = input.clone();
+
+        Ok(part_one(left, right))
+    }
+
+    fn part_two(input: &Self::Input) -> PuzzleResult<impl Display> {
+        let (left, right) = input.clone();
+
+        Ok(part_two(left, right))
+    }
+}
+
+pub fn register(registry: &mut Registry) {
+    registry.register::<Day1>();
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = "3   4
+4   3
+2   5
+1   3
+3   9
+3   3";
+
+    const EXAMPLE_SPLIT_LEFT: [u32; 6] = [3, 4, 2, 1, 3, 3];
+    const EXAMPLE_SPLIT_RIGHT: [u32; 6] = [4, 3, 5, 3, 9, 3];
+
+    #[test]
+    fn example_split() {
+        let (left, right) = split_data(EXAMPLE).unwrap();
+
+        assert_eq!(left, EXAMPLE_SPLIT_LEFT);
+        assert_eq!(right, EXAMPLE_SPLIT_RIGHT);
+    }
+
+    #[test]
+    fn parse_error() {
+        let error = Day1::parse("3   4\n4   x").unwrap_err();
+
+        assert_eq!(
+            error,
+            PuzzleError::parse(2, 5, "expected a digit, found 'x'")
+        );
+    }
+
+    #[test]
+    fn example_distance() {
+        let mut left = EXAMPLE_SPLIT_LEFT.to_vec();
+        let mut right = EXAMPLE_SPLIT_RIGHT.to_vec();
+
+        left.sort();
+        right.sort();
+
+        assert_eq!(distance(left, right), 11);
+    }
+
+    #[test]
+    fn example_similarity() {
+        let left = EXAMPLE_SPLIT_LEFT.to_vec();
+        let right = EXAMPLE_SPLIT_RIGHT.to_vec();
+
+        let mut similarity_map = HashMap::new();
+
+        similarity_sum(&mut similarity_map, left);
+        similarity_occurrences(&mut similarity_map, right);
+
+        assert_eq!(calculate_similarity(similarity_map), 31);
+    }
+}
@@ -0,0 +1,114 @@
+use std::fmt::Display;
+
+use common::*;
+
+pub struct HikingMap {
+    grid: Grid<u8>,
+}
+
+impl HikingMap {
+    fn new(raw: &str) -> Result<Self, GridError> {
+        let grid = Grid::try_from_str_with(raw, |character| {
+            character
+                .to_digit(10)
+                .and_then(|digit| digit.try_into().ok())
+        })?;
+
+        Ok(Self { grid })
+    }
+
+    fn uphill(&self, position: (usize, usize)) -> impl Iterator<Item = (usize, usize)> + '_ {
+        let current = self.grid[position];
+
+        self.grid
+            .neighbours4(position)
+            .filter(move |&neighbour| self.grid[neighbour].wrapping_sub(current) == 1)
+    }
+
+    fn trailheads(&self) -> impl Iterator<Item = (usize, usize)> + '_ {
+        self.grid
+            .positions()
+            .filter(|&position| self.grid[position] == 0)
+    }
+
+    fn part_one(&self) -> usize {
+        self.trailheads()
+            .map(|trailhead| {
+                bfs(trailhead, |&position| self.uphill(position))
+                    .nodes()
+                    .filter(|&&position| self.grid[position] == 9)
+                    .count()
+            })
+            .sum()
+    }
+
+    fn part_two(&self) -> u64 {
+        self.trailheads()
+            .map(|trailhead| {
+                count_paths(
+                    trailhead,
+                    |&position| self.uphill(position),
+                    |&position| self.grid[position] == 9,
+                )
+            })
+            .sum()
+    }
+}
+
+pub struct Day10;
+
+impl Solution for Day10 {
+    const YEAR: u16 = 2024;
+    const DAY: u8 = 10;
+
+    type Input = HikingMap;
+
+    fn parse(raw: &str) -> PuzzleResult<Self::Input> {
+        Ok(HikingMap::new(raw)?)
+    }
+
+    fn part_one(input: &Self::Input) -> PuzzleResult<impl Display> {
+        Ok(input.part_one())
+    }
+
+    fn part_two(input: &Self::Input) -> PuzzleResult<impl Display> {
+        Ok(input.part_two())
+    }
+}
+
+pub fn register(registry: &mut Registry) {
+    registry.register::<Day10>();
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE_ONE: &str = "89010123
+78121874
+87430965
+96549874
+45678903
+32019012
+01329801
+10456732";
+
+    #[test]
+    fn example_1() {
+        let map = HikingMap::new(EXAMPLE_ONE).unwrap();
+        assert_eq!(map.part_one(), 36);
+    }
+
+    #[test]
+    fn example_2() {
+        let map = HikingMap::new(EXAMPLE_ONE).unwrap();
+        assert_eq!(map.part_two(), 81);
+    }
+
+    #[test]
+    fn non_square() {
+        let map = HikingMap::new("0123456789\n1111111118").unwrap();
+        assert_eq!(map.part_one(), 1);
+        assert_eq!(map.part_two(), 1);
+    }
+}
@@ -0,0 +1,124 @@
+use std::fmt::Display;
+
+use common::{parse::*, *};
+
+fn split_number(value: usize) -> (usize, usize) {
+    let digits = value.ilog10() + 1;
+    let left_scale = 10u32.pow(digits / 2) as usize;
+    let left = value / left_scale;
+    let right = value - (left * left_scale);
+    (left, right)
+}
+
+#[derive(Debug)]
+pub struct Stones {
+    stones: Vec<usize>,
+}
+
+impl Stones {
+    fn new(raw: &str) -> Result<Self, ParseError> {
+        let stones = parse_all(raw, separated(unsigned(), literal(" ")))?;
+
+        Ok(Self { stones })
+    }
+
+    fn blink(blink: &mut Recurse<(usize, u8), usize>, (stone, depth): (usize, u8)) -> usize {
+        if depth == 0 {
+            return 1;
+        }
+
+        let depth = depth - 1;
+
+        match stone {
+            0 => blink((1, depth)),
+            stone if (stone.ilog10() + 1) % 2 == 0 => {
+                let (left, right) = split_number(stone);
+                blink((left, depth)) + blink((right, depth))
+            }
+            _ => blink((stone * 2024, depth)),
+        }
+    }
+
+    fn blink_many(&self, times: u8, memo: &mut Memo<(usize, u8), usize>) -> usize {
+        self.stones
+            .iter()
+            .map(|&stone| memo.get((stone, times), Self::blink))
+            .sum()
+    }
+
+    fn part_one(&self) -> usize {
+        self.blink_many(25, &mut Memo::new())
+    }
+
+    fn part_two(&self) -> usize {
+        self.blink_many(75, &mut Memo::new())
+    }
+}
+
+pub struct Day11;
+
+impl Solution for Day11 {
+    const YEAR: u16 = 2024;
+    const DAY: u8 = 11;
+
+    type Input = Stones;
+
+    fn parse(raw: &str) -> PuzzleResult<Self::Input> {
+        Ok(Stones::new(raw)?)
+    }
+
+    fn part_one(input: &Self::Input) -> PuzzleResult<impl Display> {
+        Ok(input.part_one())
+    }
+
+    fn part_two(input: &Self::Input) -> PuzzleResult<impl Display> {
+        Ok(input.part_two())
+    }
+}
+
+pub fn register(registry: &mut Registry) {
+    registry.register::<Day11>();
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE_ONE: &str = "125 17";
+
+    #[test]
+    fn example_1() {
+        let stones = Stones::new(EXAMPLE_ONE).unwrap();
+        assert_eq!(stones.part_one(), 55312);
+    }
+
+    #[test]
+    fn blink_cache() {
+        let stones = Stones::new(EXAMPLE_ONE).unwrap();
+        let mut memo = Memo::new();
+
+        assert_eq!(stones.blink_many(6, &mut memo), 22);
+        assert_eq!(memo.stats().misses as usize, memo.len());
+        assert!(memo.stats().hits > 0);
+
+        let misses = memo.stats().misses;
+        assert_eq!(stones.blink_many(6, &mut memo), 22);
+        assert_eq!(memo.stats().misses, misses);
+    }
+
+    #[test]
+    fn split_number() {
+        let (left, right) = super::split_number(123456);
+        assert_eq!(left, 123);
+        assert_eq!(right, 456);
+    }
+
+    #[test]
+    fn parse_error() {
+        let error = Day11::parse("125 -17").unwrap_err();
+        assert_eq!(
+            error,
+            PuzzleError::parse(1, 5, "expected a digit, found '-17'")
+        );
+    }
+}
@@ -0,0 +1,239 @@
+use std::{collections::HashSet, fmt::Display};
+
+use common::*;
+
+pub struct Garden {
+    plots: Grid<char>,
+}
+
+/// Mirrors `direction` across the diagonal, so sides run across the direction they face.
+fn side_direction(direction: Direction4) -> Direction4 {
+    match direction {
+        Direction4::Up => Direction4::Left,
+        Direction4::Down => Direction4::Right,
+        Direction4::Left => Direction4::Up,
+        Direction4::Right => Direction4::Down,
+    }
+}
+
+impl Garden {
+    fn new(raw: &str) -> Result<Self, GridError> {
+        let plots = Grid::from_str_with(raw, |character| character)?;
+
+        Ok(Self { plots })
+    }
+
+    fn get_offset(&self, position: (usize, usize), direction: Direction4) -> Option<char> {
+        let new_position = Point::from(position).checked_step(direction)?;
+
+        self.plots.get(new_position).copied()
+    }
+
+    fn is_plot_same(&self, position: (usize, usize), direction: Direction4) -> bool {
+        self.get_offset(position, direction)
+            .map(|plot| self.plots[position] == plot)
+            .unwrap_or_default()
+    }
+
+    /// Every plot connected to `position` with the same plant, marking them as visited.
+    fn flood_region(
+        &self,
+        position: (usize, usize),
+        visted: &mut HashSet<(usize, usize)>,
+    ) -> Vec<(usize, usize)> {
+        let region = bfs(position, |&plot| {
+            Direction4::ALL
+                .into_iter()
+                .filter(move |&direction| self.is_plot_same(plot, direction))
+                .map(move |direction| Point::from(plot).wrapping_step(direction).into())
+        })
+        .nodes()
+        .copied()
+        .collect::<Vec<_>>();
+
+        visted.extend(&region);
+        region
+    }
+
+    fn scan_region(
+        &self,
+        position: (usize, usize),
+        visted: &mut HashSet<(usize, usize)>,
+    ) -> Region {
+        let region = self.flood_region(position, visted);
+
+        let perimeter = region
+            .iter()
+            .flat_map(|&plot| Direction4::ALL.map(|direction| (plot, direction)))
+            .filter(|&(plot, direction)| !self.is_plot_same(plot, direction))
+            .count();
+
+        Region {
+            area: region.len() as u32,
+            perimeter: perimeter as u32,
+        }
+    }
+
+    fn scan_side(
+        &self,
+        position: (usize, usize),
+        direction: Direction4,
+    ) -> ((usize, usize), (usize, usize)) {
+        let mut current = position;
+        let current_plot = self.plots[current];
+        let scan_direction = side_direction(direction);
+
+        let mut positive_end = position;
+
+        while let Some(search_plot) = self.get_offset(current, scan_direction) {
+            current = Point::from(current).wrapping_step(scan_direction).into();
+
+            if search_plot != current_plot || self.is_plot_same(current, direction) {
+                positive_end = current;
+            }
+        }
+
+        let scan_direction = scan_direction.opposite();
+
+        let mut negative_end = position;
+
+        while let Some(search_plot) = self.get_offset(current, scan_direction) {
+            current = Point::from(current).wrapping_step(scan_direction).into();
+
+            if search_plot != current_plot || self.is_plot_same(current, direction) {
+                negative_end = current;
+            }
+        }
+
+        (
+            (
+                positive_end.0.min(negative_end.0),
+                positive_end.1.min(negative_end.1),
+            ),
+            (
+                positive_end.0.max(negative_end.0),
+                positive_end.1.max(negative_end.1),
+            ),
+        )
+    }
+
+    fn scan_region_sides(
+        &self,
+        position: (usize, usize),
+        visted: &mut HashSet<(usize, usize)>,
+    ) -> Region {
+        let region = self.flood_region(position, visted);
+
+        let sides = region
+            .iter()
+            .flat_map(|&plot| Direction4::ALL.map(|direction| (plot, direction)))
+            .filter(|&(plot, direction)| !self.is_plot_same(plot, direction))
+            .map(|(plot, direction)| self.scan_side(plot, direction))
+            .filter(|(side_lower, side_upper)| side_lower != side_upper)
+            .collect::<HashSet<_>>();
+
+        Region {
+            area: region.len() as u32,
+            perimeter: sides.len() as u32,
+        }
+    }
+
+    fn regions(
+        &self,
+        scan: impl Fn(&Self, (usize, usize), &mut HashSet<(usize, usize)>) -> Region,
+    ) -> u32 {
+        let area = self.plots.width() * self.plots.height();
+        let mut visted = HashSet::<(usize, usize)>::with_capacity(area);
+
+        self.plots
+            .positions()
+            .filter_map(|position| {
+                if !visted.contains(&position) {
+                    Some(scan(self, position, &mut visted))
+                } else {
+                    None
+                }
+            })
+            .map(|region| region.cost())
+            .sum()
+    }
+
+    fn part_one(&self) -> u32 {
+        self.regions(Self::scan_region)
+    }
+
+    fn part_two(&self) -> u32 {
+        self.regions(Self::scan_region_sides)
+    }
+}
+
+#[derive(Debug)]
+struct Region {
+    perimeter: u32,
+    area: u32,
+}
+
+impl Region {
+    fn cost(&self) -> u32 {
+        self.perimeter * self.area
+    }
+}
+
+pub struct Day12;
+
+impl Solution for Day12 {
+    const YEAR: u16 = 2024;
+    const DAY: u8 = 12;
+
+    type Input = Garden;
+
+    fn parse(raw: &str) -> PuzzleResult<Self::Input> {
+        Ok(Garden::new(raw)?)
+    }
+
+    fn part_one(input: &Self::Input) -> PuzzleResult<impl Display> {
+        Ok(input.part_one())
+    }
+
+    fn part_two(input: &Self::Input) -> PuzzleResult<impl Display> {
+        Ok(input.part_two())
+    }
+}
+
+pub fn register(registry: &mut Registry) {
+    registry.register::<Day12>();
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE_ONE: &str = "RRRRIICCFF
+RRRRIICCCF
+VVRRRCCFFF
+VVRCCCJFFF
+VVVVCJJCFE
+VVIVCCJJEE
+VVIIICJJEE
+MIIIIIJJEE
+MIIISIJEEE
+MMMISSJEEE";
+
+    const EXAMPLE_TWO: &str = "EEEEE
+EXXXX
+EEEEE
+EXXXX
+EEEEE";
+
+    #[test]
+    fn example_1() {
+        let garden = Garden::new(EXAMPLE_ONE).unwrap();
+        assert_eq!(garden.part_one(), 1930);
+    }
+
+    #[test]
+    fn example_2() {
+        let garden = Garden::new(EXAMPLE_TWO).unwrap();
+        assert_eq!(garden.part_two(), 236);
+    }
+}
@@ -0,0 +1,293 @@
+use std::fmt::Display;
+
+use common::{parse::*, *};
+
+const CONVERSION_OFFSET: u64 = 10_000_000_000_000;
+const A_COST: i128 = 3;
+const B_COST: i128 = 1;
+
+fn widen((x, y): (u64, u64)) -> (i128, i128) {
+    (x as i128, y as i128)
+}
+
+#[derive(Debug, Eq, PartialEq, Clone)]
+pub struct Game {
+    button_a: (u64, u64),
+    button_b: (u64, u64),
+    prize: (u64, u64),
+}
+
+impl Game {
+    fn parser<'a>() -> impl Parser<'a, Self> {
+        let button = |name| {
+            preceded(
+                pair(literal(name), literal(": ")),
+                separated_pair(
+                    preceded(literal("X+"), unsigned()),
+                    literal(", "),
+                    preceded(literal("Y+"), unsigned()),
+                ),
+            )
+        };
+
+        let prize = preceded(
+            literal("Prize: "),
+            separated_pair(
+                key_value("X", unsigned()),
+                literal(", "),
+                key_value("Y", unsigned()),
+            ),
+        );
+
+        map(
+            pair(
+                terminated(button("Button A"), line_break()),
+                separated_pair(button("Button B"), line_break(), prize),
+            ),
+            |(button_a, (button_b, prize))| Self {
+                button_a,
+                button_b,
+                prize,
+            },
+        )
+    }
+
+    /// Presses of A and B that win the prize for the fewest tokens.
+    fn presses(&self) -> Result<(i128, i128), NoSolution> {
+        let (button_a_x, button_a_y) = widen(self.button_a);
+        let (button_b_x, button_b_y) = widen(self.button_b);
+        let (prize_x, prize_y) = widen(self.prize);
+
+        let matrix = [vec![button_a_x, button_b_x], vec![button_a_y, button_b_y]];
+
+        match solve_integer_system(&matrix, &[prize_x, prize_y]) {
+            Ok(presses) if presses.iter().any(|&press| press < 0) => Err(NoSolution::Negative),
+            Ok(presses) => Ok((presses[0], presses[1])),
+            // Both buttons move along the same line, so only one axis needs solving.
+            Err(NoSolution::Singular) => {
+                // A button that doesn't move can't say which line that is.
+                let (line_x, line_y) = if (button_a_x, button_a_y) != (0, 0) {
+                    (button_a_x, button_a_y)
+                } else {
+                    (button_b_x, button_b_y)
+                };
+
+                if (line_x, line_y) == (0, 0) {
+                    return if (prize_x, prize_y) == (0, 0) {
+                        Ok((0, 0))
+                    } else {
+                        Err(NoSolution::Inconsistent)
+                    };
+                }
+
+                if line_x * prize_y != line_y * prize_x {
+                    return Err(NoSolution::Inconsistent);
+                }
+
+                if line_x != 0 {
+                    min_cost_diophantine(button_a_x, button_b_x, prize_x, A_COST, B_COST)
+                } else {
+                    min_cost_diophantine(button_a_y, button_b_y, prize_y, A_COST, B_COST)
+                }
+            }
+            Err(error) => Err(error),
+        }
+    }
+
+    fn min_tokens(&self) -> Result<u64, NoSolution> {
+        let (button_a, button_b) = self.presses()?;
+
+        Ok((button_a * A_COST + button_b * B_COST) as u64)
+    }
+
+    fn correct_conversion(&mut self) {
+        self.prize.0 += CONVERSION_OFFSET;
+        self.prize.1 += CONVERSION_OFFSET;
+    }
+}
+
+fn parse_input(raw: &str) -> Result<Vec<Game>, ParseError> {
+    parse_all(raw, blocks(Game::parser()))
+}
+
+fn part_one(games: &[Game]) -> u64 {
+    games.iter().filter_map(|game| game.min_tokens().ok()).sum()
+}
+
+fn part_two(mut games: Vec<Game>) -> u64 {
+    games.iter_mut().for_each(Game::correct_conversion);
+    games.iter().filter_map(|game| game.min_tokens().ok()).sum()
+}
+
+pub struct Day13;
+
+impl Solution for Day13 {
+    const YEAR: u16 = 2024;
+    const DAY: u8 = 13;
+
+    type Input = Vec<Game>;
+
+    fn parse(raw: &str) -> PuzzleResult<Self::Input> {
+        Ok(parse_input(raw)?)
+    }
+
+    fn part_one(input: &Self::Input) -> PuzzleResult<impl Display> {
+        Ok(part_one(input))
+    }
+
+    fn part_two(input: &Self::Input) -> PuzzleResult<impl Display> {
+        Ok(part_two(input.clone()))
+    }
+}
+
+pub fn register(registry: &mut Registry) {
+    registry.register::<Day13>();
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE_ONE: &str = "Button A: X+94, Y+34
+Button B: X+22, Y+67
+Prize: X=8400, Y=5400
+
+Button A: X+26, Y+66
+Button B: X+67, Y+21
+Prize: X=12748, Y=12176
+
+Button A: X+17, Y+86
+Button B: X+84, Y+37
+Prize: X=7870, Y=6450
+
+Button A: X+69, Y+23
+Button B: X+27, Y+71
+Prize: X=18641, Y=10279";
+
+    #[test]
+    fn parse() {
+        let games = parse_input(EXAMPLE_ONE).unwrap();
+        let expected = vec![
+            Game {
+                button_a: (94, 34),
+                button_b: (22, 67),
+                prize: (8400, 5400),
+            },
+            Game {
+                button_a: (26, 66),
+                button_b: (67, 21),
+                prize: (12748, 12176),
+            },
+            Game {
+                button_a: (17, 86),
+                button_b: (84, 37),
+                prize: (7870, 6450),
+            },
+            Game {
+                button_a: (69, 23),
+                button_b: (27, 71),
+                prize: (18641, 10279),
+            },
+        ];
+
+        assert_eq!(games, expected);
+    }
+
+    #[test]
+    fn parse_error() {
+        let error =
+            parse_input("Button A: X+94, Y+34\nButton B: X+22, Y=67\nPrize: X=8400, Y=5400")
+                .unwrap_err();
+
+        assert_eq!((error.line, error.column), (2, 17));
+        assert_eq!(error.expected, "\"Y+\"");
+    }
+
+    #[test]
+    fn example_1() {
+        let games = parse_input(EXAMPLE_ONE).unwrap();
+        assert_eq!(part_one(&games), 480);
+    }
+
+    #[test]
+    fn unwinnable() {
+        let games = parse_input(EXAMPLE_ONE).unwrap();
+
+        assert_eq!(games[0].min_tokens(), Ok(280));
+        assert_eq!(games[1].min_tokens(), Err(NoSolution::NonIntegral));
+        assert_eq!(games[3].min_tokens(), Err(NoSolution::NonIntegral));
+    }
+
+    #[test]
+    fn converted() {
+        let mut games = parse_input(EXAMPLE_ONE).unwrap();
+        games.iter_mut().for_each(Game::correct_conversion);
+
+        assert!(games[0].min_tokens().is_err());
+        assert_eq!(games[1].min_tokens(), Ok(459236326669));
+        assert!(games[2].min_tokens().is_err());
+        assert_eq!(games[3].min_tokens(), Ok(416082282239));
+    }
+
+    #[test]
+    fn parallel_buttons() {
+        let game = |prize| Game {
+            button_a: (3, 6),
+            button_b: (1, 2),
+            prize,
+        };
+
+        assert_eq!(game((7, 14)).min_tokens(), Ok(7));
+        assert_eq!(game((9, 18)).min_tokens(), Ok(9));
+        assert_eq!(game((7, 15)).min_tokens(), Err(NoSolution::Inconsistent));
+        assert_eq!(
+            Game {
+                button_a: (4, 4),
+                button_b: (6, 6),
+                prize: (7, 7),
+            }
+            .min_tokens(),
+            Err(NoSolution::NonIntegral)
+        );
+    }
+
+    #[test]
+    fn still_button() {
+        let game = |button_a, button_b, prize| Game {
+            button_a,
+            button_b,
+            prize,
+        };
+
+        assert_eq!(game((2, 4), (0, 0), (6, 12)).min_tokens(), Ok(9));
+        assert_eq!(game((0, 0), (2, 4), (6, 12)).min_tokens(), Ok(3));
+        assert_eq!(
+            game((0, 0), (2, 4), (7, 12)).min_tokens(),
+            Err(NoSolution::Inconsistent)
+        );
+        assert_eq!(
+            game((0, 0), (0, 0), (1, 0)).min_tokens(),
+            Err(NoSolution::Inconsistent)
+        );
+        assert_eq!(game((0, 3), (0, 1), (0, 6)).min_tokens(), Ok(6));
+    }
+
+    #[test]
+    fn negative_presses() {
+        let game = Game {
+            button_a: (1, 0),
+            button_b: (0, 1),
+            prize: (5, 0),
+        };
+
+        assert_eq!(game.min_tokens(), Ok(15));
+
+        let game = Game {
+            button_a: (2, 1),
+            button_b: (1, 2),
+            prize: (1, 5),
+        };
+
+        assert_eq!(game.min_tokens(), Err(NoSolution::Negative));
+    }
+}
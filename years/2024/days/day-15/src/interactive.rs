@@ -0,0 +1,229 @@
+//! Drives the robot by hand, which is the quickest way to reproduce a bad push.
+
+use std::{
+    io::{self, Write},
+    marker::PhantomData,
+};
+
+use common::{viz::Frame, *};
+use crossterm::{
+    cursor,
+    event::{self, Event, KeyCode, KeyEvent, KeyEventKind, KeyModifiers},
+    execute, queue,
+    terminal::{self, ClearType},
+};
+
+use crate::{IsBox, NormalWarehouse, Warehouse, WideWarehouse};
+
+const HELP: &str = "Arrows or ^v<> to move, u to undo, q to quit";
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Command {
+    Move(Direction4),
+    Undo,
+    Quit,
+}
+
+impl Command {
+    fn from_key(key: KeyEvent) -> Option<Self> {
+        match key.code {
+            KeyCode::Char('c') if key.modifiers.contains(KeyModifiers::CONTROL) => Some(Self::Quit),
+            KeyCode::Up | KeyCode::Char('^') => Some(Self::Move(Direction4::Up)),
+            KeyCode::Down | KeyCode::Char('v') => Some(Self::Move(Direction4::Down)),
+            KeyCode::Left | KeyCode::Char('<') => Some(Self::Move(Direction4::Left)),
+            KeyCode::Right | KeyCode::Char('>') => Some(Self::Move(Direction4::Right)),
+            KeyCode::Char('u') | KeyCode::Backspace => Some(Self::Undo),
+            KeyCode::Char('q') | KeyCode::Esc => Some(Self::Quit),
+            _ => None,
+        }
+    }
+}
+
+/// A warehouse along with every earlier state of it, so moves can be taken back.
+#[derive(Debug)]
+struct Driver<T, W> {
+    warehouse: W,
+    history: Vec<W>,
+    boxes: usize,
+    tile: PhantomData<T>,
+}
+
+impl<T: IsBox, W: Warehouse<T> + Clone> Driver<T, W> {
+    fn new(warehouse: W) -> Self {
+        Self {
+            boxes: warehouse.box_count(),
+            warehouse,
+            history: Vec::new(),
+            tile: PhantomData,
+        }
+    }
+
+    /// Returns false once the driver should stop.
+    fn apply(&mut self, command: Command) -> bool {
+        match command {
+            Command::Move(direction) => {
+                self.history.push(self.warehouse.clone());
+                self.warehouse.step(direction);
+            }
+            Command::Undo => {
+                if let Some(previous) = self.history.pop() {
+                    self.warehouse = previous;
+                }
+            }
+            Command::Quit => return false,
+        }
+
+        true
+    }
+
+    /// The map with its GPS sum, and anything the last push broke.
+    fn frame(&self) -> Frame {
+        let mut caption = format!(
+            "GPS sum: {}, moves: {}\n{}",
+            self.warehouse.box_gps_sum(),
+            self.history.len(),
+            HELP
+        );
+
+        if let Err(error) = self.warehouse.check_invariants(self.boxes) {
+            caption.push_str(&format!("\n{}", error));
+        }
+
+        self.warehouse.frame().with_caption(caption)
+    }
+}
+
+/// Leaves raw mode even if a push panics.
+struct RawTerminal;
+
+impl RawTerminal {
+    fn enter() -> io::Result<Self> {
+        terminal::enable_raw_mode()?;
+        execute!(io::stdout(), terminal::EnterAlternateScreen, cursor::Hide)?;
+        Ok(Self)
+    }
+}
+
+impl Drop for RawTerminal {
+    fn drop(&mut self) {
+        let _ = execute!(io::stdout(), cursor::Show, terminal::LeaveAlternateScreen);
+        let _ = terminal::disable_raw_mode();
+    }
+}
+
+fn drive<T: IsBox, W: Warehouse<T> + Clone>(warehouse: W) -> io::Result<usize> {
+    let mut driver = Driver::new(warehouse);
+    let mut stdout = io::stdout();
+    let terminal = RawTerminal::enter()?;
+
+    loop {
+        // Raw mode doesn't return the cursor to the start of the line by itself.
+        let frame = driver.frame().to_string().replace('\n', "\r\n");
+        queue!(
+            stdout,
+            cursor::MoveTo(0, 0),
+            terminal::Clear(ClearType::All)
+        )?;
+        write!(stdout, "{}", frame)?;
+        stdout.flush()?;
+
+        let Event::Key(key) = event::read()? else {
+            continue;
+        };
+
+        if key.kind != KeyEventKind::Press {
+            continue;
+        }
+
+        if let Some(command) = Command::from_key(key) {
+            if !driver.apply(command) {
+                break;
+            }
+        }
+    }
+
+    drop(terminal);
+    Ok(driver.warehouse.box_gps_sum())
+}
+
+/// Lets arrow keys or `^v<>` move the robot around the input's warehouse, widened if `wide`,
+/// returning the GPS sum where it was left. Maps that are already wide are used as they are.
+pub fn interactive(raw: &str, wide: bool) -> io::Result<usize> {
+    let invalid = |error: PuzzleError| io::Error::new(io::ErrorKind::InvalidData, error);
+
+    if raw.contains('[') {
+        return drive(WideWarehouse::from_raw(raw).map_err(invalid)?);
+    }
+
+    let warehouse = NormalWarehouse::from_raw(raw).map_err(invalid)?;
+
+    if wide {
+        drive(warehouse.widen())
+    } else {
+        drive(warehouse)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const WAREHOUSE: &str = "########
+#..O.O.#
+##@.O..#
+#...O..#
+########
+
+";
+
+    fn key(code: KeyCode) -> KeyEvent {
+        KeyEvent::new(code, KeyModifiers::NONE)
+    }
+
+    #[test]
+    fn keys() {
+        assert_eq!(
+            Command::from_key(key(KeyCode::Up)),
+            Some(Command::Move(Direction4::Up))
+        );
+        assert_eq!(
+            Command::from_key(key(KeyCode::Char('<'))),
+            Some(Command::Move(Direction4::Left))
+        );
+        assert_eq!(
+            Command::from_key(key(KeyCode::Char('u'))),
+            Some(Command::Undo)
+        );
+        assert_eq!(
+            Command::from_key(KeyEvent::new(KeyCode::Char('c'), KeyModifiers::CONTROL)),
+            Some(Command::Quit)
+        );
+        assert_eq!(Command::from_key(key(KeyCode::Char('x'))), None);
+        assert_eq!(Command::from_key(key(KeyCode::Char('U'))), None);
+        assert_eq!(Command::from_key(key(KeyCode::Char('d'))), None);
+    }
+
+    #[test]
+    fn undo() {
+        let start = NormalWarehouse::from_raw(WAREHOUSE).unwrap().widen();
+        let mut driver = Driver::new(start.clone());
+
+        for direction in [Direction4::Right, Direction4::Right, Direction4::Down] {
+            assert!(driver.apply(Command::Move(direction)));
+        }
+
+        let moved = driver.frame();
+        assert_ne!(moved.plain(), start.frame().plain());
+
+        driver.apply(Command::Undo);
+        driver.apply(Command::Move(Direction4::Down));
+        assert_eq!(driver.frame(), moved);
+
+        for _ in 0..5 {
+            driver.apply(Command::Undo);
+        }
+
+        assert_eq!(driver.warehouse.frame(), start.frame());
+        assert!(!driver.apply(Command::Quit));
+    }
+}
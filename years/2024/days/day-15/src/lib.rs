@@ -0,0 +1,1386 @@
+use std::{
+    collections::{HashSet, VecDeque},
+    error::Error,
+    fmt::{self, Display},
+    time::Duration,
+};
+
+use common::{
+    image::{Image, Recording},
+    viz::{self, Cell, Colour, Frame},
+    *,
+};
+
+mod interactive;
+
+pub use interactive::*;
+
+trait IsBox {
+    fn is_box(&self) -> bool;
+
+    fn to_char(&self) -> char;
+
+    fn cell(&self) -> Cell;
+}
+
+#[derive(Debug, Clone, Copy)]
+enum Tile {
+    Wall,
+    Box,
+}
+
+impl Tile {
+    fn from_char(value: char) -> Option<Self> {
+        match value {
+            '#' => Some(Self::Wall),
+            'O' => Some(Self::Box),
+            _ => None,
+        }
+    }
+
+    fn widen(value: Option<Self>) -> [Option<WideTile>; 2] {
+        match value {
+            Some(Self::Wall) => [Some(WideTile::Wall), Some(WideTile::Wall)],
+            Some(Self::Box) => [Some(WideTile::BoxLeft), Some(WideTile::BoxRight)],
+            None => [None, None],
+        }
+    }
+}
+
+impl IsBox for Tile {
+    fn is_box(&self) -> bool {
+        match self {
+            Self::Box => true,
+            Self::Wall => false,
+        }
+    }
+
+    fn to_char(&self) -> char {
+        match self {
+            Self::Wall => '#',
+            Self::Box => 'O',
+        }
+    }
+
+    fn cell(&self) -> Cell {
+        match self {
+            Self::Wall => Cell::new('#', Colour::Grey),
+            Self::Box => Cell::new('O', Colour::Yellow),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy)]
+enum WideTile {
+    Wall,
+    BoxLeft,
+    BoxRight,
+}
+
+impl WideTile {
+    fn from_char(value: char) -> Option<Self> {
+        match value {
+            '#' => Some(Self::Wall),
+            '[' => Some(Self::BoxLeft),
+            ']' => Some(Self::BoxRight),
+            _ => None,
+        }
+    }
+}
+
+impl IsBox for WideTile {
+    fn is_box(&self) -> bool {
+        match self {
+            Self::Wall | Self::BoxRight => false,
+            Self::BoxLeft => true,
+        }
+    }
+
+    fn to_char(&self) -> char {
+        match self {
+            Self::Wall => '#',
+            Self::BoxLeft => '[',
+            Self::BoxRight => ']',
+        }
+    }
+
+    fn cell(&self) -> Cell {
+        match self {
+            Self::Wall => Cell::new('#', Colour::Grey),
+            Self::BoxLeft => Cell::new('[', Colour::Yellow),
+            Self::BoxRight => Cell::new(']', Colour::Yellow),
+        }
+    }
+}
+
+/// Something a push broke that should hold after every move.
+#[derive(Debug, Clone, PartialEq, Eq)]
+enum InvariantError {
+    BoxCount { expected: usize, found: usize },
+    SplitBox(Point<usize>),
+    RobotOnTile(Point<usize>),
+}
+
+impl Display for InvariantError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::BoxCount { expected, found } => {
+                write!(f, "Expected {} boxes but found {}", expected, found)
+            }
+            Self::SplitBox(position) => {
+                write!(f, "Box half at {} is missing its other half", position)
+            }
+            Self::RobotOnTile(position) => write!(f, "Robot is inside a tile at {}", position),
+        }
+    }
+}
+
+impl Error for InvariantError {}
+
+trait Warehouse<T: IsBox> {
+    /// What to call the GIF of the robot's moves.
+    const RECORDING: &'static str;
+
+    fn get_tile(&self, position: Point<usize>) -> Option<&T> {
+        let Point { x, y } = position;
+        self.get_tiles()
+            .get(y)
+            .and_then(|tiles| tiles.get(x).and_then(Option::as_ref))
+    }
+
+    /// The next position in `direction`, or `None` past the edge of the map, which blocks
+    /// like a wall.
+    fn neighbour(&self, position: Point<usize>, direction: Direction4) -> Option<Point<usize>> {
+        position.bounded_step(direction, self.get_width(), self.get_height())
+    }
+
+    fn push(&mut self, position: Point<usize>, direction: Direction4) -> bool;
+
+    fn step(&mut self, direction: Direction4);
+
+    fn box_gps_sum(&self) -> usize {
+        width_height_2d_iter(self.get_width(), self.get_height())
+            .map(Point::from)
+            .filter(|position| self.is_box(*position))
+            .map(Self::gps_location)
+            .sum()
+    }
+
+    fn box_count(&self) -> usize {
+        width_height_2d_iter(self.get_width(), self.get_height())
+            .filter(|&position| self.is_box(position.into()))
+            .count()
+    }
+
+    /// Where half a box has lost its other half, which only wide warehouses can do.
+    fn split_box(&self) -> Option<Point<usize>> {
+        None
+    }
+
+    /// Checks no box has been lost, duplicated or split, and the robot isn't inside anything,
+    /// given how many boxes there were to start with.
+    fn check_invariants(&self, boxes: usize) -> Result<(), InvariantError> {
+        let found = self.box_count();
+
+        if found != boxes {
+            return Err(InvariantError::BoxCount {
+                expected: boxes,
+                found,
+            });
+        }
+
+        if let Some(position) = self.split_box() {
+            return Err(InvariantError::SplitBox(position));
+        }
+
+        let robot = self.get_robot();
+
+        if self.get_tile(robot).is_some() {
+            return Err(InvariantError::RobotOnTile(robot));
+        }
+
+        Ok(())
+    }
+
+    fn get_tiles(&self) -> &Vec<Vec<Option<T>>>;
+
+    fn get_tiles_mut(&mut self) -> &mut Vec<Vec<Option<T>>>;
+
+    fn get_directions(&self) -> &Vec<Direction4>;
+
+    fn get_width(&self) -> usize;
+
+    fn get_height(&self) -> usize;
+
+    fn get_robot(&self) -> Point<usize>;
+
+    fn frame(&self) -> Frame {
+        let mut frame = Frame::new(self.get_width(), self.get_height());
+
+        for (y, tiles) in self.get_tiles().iter().enumerate() {
+            for (x, tile) in tiles.iter().enumerate() {
+                let cell = tile
+                    .as_ref()
+                    .map_or(Cell::new('.', Colour::Grey), IsBox::cell);
+                frame.set((x, y), cell);
+            }
+        }
+
+        frame.set(self.get_robot(), Cell::new('@', Colour::Red));
+        frame
+    }
+
+    /// Writes the map the way the puzzle draws it.
+    fn fmt_map(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let robot = self.get_robot();
+
+        for (y, tiles) in self.get_tiles().iter().enumerate() {
+            if y > 0 {
+                writeln!(f)?;
+            }
+
+            for (x, tile) in tiles.iter().enumerate() {
+                let character = if Point::new(x, y) == robot {
+                    '@'
+                } else {
+                    tile.as_ref().map_or('.', IsBox::to_char)
+                };
+
+                write!(f, "{}", character)?;
+            }
+        }
+
+        Ok(())
+    }
+
+    fn is_box(&self, position: Point<usize>) -> bool {
+        let Point { x, y } = position;
+        self.get_tiles()[y][x]
+            .as_ref()
+            .map(IsBox::is_box)
+            .unwrap_or_default()
+    }
+
+    fn push_tile(&mut self, from: Point<usize>, to: Point<usize>) {
+        let Point {
+            x: from_x,
+            y: from_y,
+        } = from;
+        let Point { x: to_x, y: to_y } = to;
+        let tile = self.get_tiles_mut()[from_y][from_x].take().unwrap();
+        let target = &mut self.get_tiles_mut()[to_y][to_x];
+        debug_assert!(target.is_none(), "Pushed a tile onto another at {:?}", to);
+        *target = Some(tile);
+    }
+
+    fn step_all(&mut self) -> usize {
+        let mut recording = Recording::new(Self::RECORDING, Duration::from_millis(20));
+        recording.record(|| Image::from_frame(&self.frame()).scaled(4));
+
+        let boxes = self.box_count();
+
+        self.get_directions()
+            .clone()
+            .into_iter()
+            .enumerate()
+            .for_each(|(index, direction)| {
+                self.step(direction);
+
+                // Scans the whole map, so it's opt in rather than on for every debug build.
+                if cfg!(feature = "check-invariants") {
+                    debug_assert_eq!(
+                        self.check_invariants(boxes),
+                        Ok(()),
+                        "after move {} ({})",
+                        index + 1,
+                        direction
+                    );
+                }
+                viz::show(|| self.frame().with_caption(format!("Move {}", direction)));
+                recording.record(|| Image::from_frame(&self.frame()).scaled(4));
+            });
+
+        recording.save();
+        self.box_gps_sum()
+    }
+
+    fn gps_location(position: Point<usize>) -> usize {
+        let Point { x, y } = position;
+        x + y * 100
+    }
+}
+
+type Tiles<T> = Vec<Vec<Option<T>>>;
+
+/// Splits a puzzle into its map, where the robot starts, and the robot's moves.
+fn parse_warehouse<T>(
+    raw: &str,
+    from_char: impl Fn(char) -> Option<T>,
+) -> PuzzleResult<(Tiles<T>, Point<usize>, Vec<Direction4>)> {
+    let (map, movements) = raw.split_once("\n\n").unwrap_or((raw, ""));
+
+    let mut robot = None;
+    let mut tiles: Tiles<T> = Vec::new();
+
+    for (y, line) in map.lines().enumerate() {
+        let mut row = Vec::with_capacity(line.len());
+
+        for (x, value) in line.chars().enumerate() {
+            match value {
+                '@' if robot.is_some() => {
+                    return Err(PuzzleError::parse(y + 1, x + 1, "a second robot"));
+                }
+                '@' => {
+                    robot = Some(Point::new(x, y));
+                    row.push(None);
+                }
+                '.' => row.push(None),
+                _ => {
+                    let tile = from_char(value).ok_or(GridError::InvalidCell {
+                        x,
+                        y,
+                        character: value,
+                    })?;
+                    row.push(Some(tile));
+                }
+            }
+        }
+
+        if let Some(expected) = tiles.first().map(Vec::len) {
+            if row.len() != expected {
+                return Err(GridError::Ragged {
+                    row: y,
+                    expected,
+                    found: row.len(),
+                }
+                .into());
+            }
+        }
+
+        tiles.push(row);
+    }
+
+    if tiles.first().is_none_or(Vec::is_empty) {
+        return Err(PuzzleError::parse(1, 1, "the map is empty"));
+    }
+
+    let robot = robot.ok_or_else(|| PuzzleError::parse(1, 1, "no robot '@' on the map"))?;
+
+    // Moves start after the map and the blank line below it.
+    let first_line = tiles.len() + 2;
+    let mut directions = Vec::with_capacity(movements.len());
+
+    for (index, line) in movements.lines().enumerate() {
+        for (x, value) in line.chars().enumerate() {
+            let direction = Direction4::from_arrow(value).ok_or_else(|| {
+                PuzzleError::parse(
+                    first_line + index,
+                    x + 1,
+                    format!("expected a move, found {:?}", value),
+                )
+            })?;
+            directions.push(direction);
+        }
+    }
+
+    Ok((tiles, robot, directions))
+}
+
+#[derive(Debug, Clone)]
+pub struct NormalWarehouse {
+    tiles: Vec<Vec<Option<Tile>>>,
+    width: usize,
+    height: usize,
+    robot: Point<usize>,
+    directions: Vec<Direction4>,
+}
+
+impl NormalWarehouse {
+    fn from_raw(raw: &str) -> PuzzleResult<Self> {
+        let (tiles, robot, directions) = parse_warehouse(raw, Tile::from_char)?;
+
+        Ok(Self {
+            width: tiles.first().map_or(0, Vec::len),
+            height: tiles.len(),
+            tiles,
+            robot,
+            directions,
+        })
+    }
+
+    fn widen(self) -> WideWarehouse {
+        let Point { x, y } = self.robot;
+        let robot = Point::new(x * 2, y);
+
+        let tiles = self
+            .tiles
+            .into_iter()
+            .map(|line| line.into_iter().flat_map(Tile::widen).collect())
+            .collect();
+
+        WideWarehouse {
+            width: self.width * 2,
+            height: self.height,
+            directions: self.directions,
+            tiles,
+            robot,
+        }
+    }
+}
+
+impl Display for NormalWarehouse {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.fmt_map(f)
+    }
+}
+
+impl Warehouse<Tile> for NormalWarehouse {
+    const RECORDING: &'static str = "warehouse.gif";
+
+    fn push(&mut self, position: Point<usize>, direction: Direction4) -> bool {
+        let Some(push_position) = self.neighbour(position, direction) else {
+            return false;
+        };
+
+        let can_push = match self.get_tile(push_position) {
+            Some(Tile::Wall) => false,
+            Some(Tile::Box) => self.push(push_position, direction),
+            None => true,
+        };
+
+        if can_push {
+            self.push_tile(position, push_position);
+        }
+
+        can_push
+    }
+
+    fn step(&mut self, direction: Direction4) {
+        let Some(new_position) = self.neighbour(self.robot, direction) else {
+            return;
+        };
+
+        let can_move = match self.get_tile(new_position) {
+            Some(Tile::Wall) => false,
+            Some(Tile::Box) => self.push(new_position, direction),
+            None => true,
+        };
+
+        if can_move {
+            self.robot = new_position;
+        }
+    }
+
+    fn get_tiles(&self) -> &Vec<Vec<Option<Tile>>> {
+        &self.tiles
+    }
+
+    fn get_tiles_mut(&mut self) -> &mut Vec<Vec<Option<Tile>>> {
+        &mut self.tiles
+    }
+
+    fn get_directions(&self) -> &Vec<Direction4> {
+        &self.directions
+    }
+
+    fn get_width(&self) -> usize {
+        self.width
+    }
+
+    fn get_height(&self) -> usize {
+        self.height
+    }
+
+    fn get_robot(&self) -> Point<usize> {
+        self.robot
+    }
+}
+
+#[derive(Debug, Clone)]
+struct WideWarehouse {
+    tiles: Vec<Vec<Option<WideTile>>>,
+    width: usize,
+    height: usize,
+    robot: Point<usize>,
+    directions: Vec<Direction4>,
+}
+
+impl WideWarehouse {
+    /// Parses a map that's already wide, so boxes needn't line up with the normal grid.
+    fn from_raw(raw: &str) -> PuzzleResult<Self> {
+        let (tiles, robot, directions) = parse_warehouse(raw, WideTile::from_char)?;
+
+        let warehouse = Self {
+            width: tiles.first().map_or(0, Vec::len),
+            height: tiles.len(),
+            tiles,
+            robot,
+            directions,
+        };
+
+        // Pushing half a box would leave the other half behind.
+        if let Some(Point { x, y }) = warehouse.split_box() {
+            return Err(PuzzleError::parse(y + 1, x + 1, "half a box"));
+        }
+
+        Ok(warehouse)
+    }
+
+    /// The left halves of every box moved by pushing `position` up or down, nearest first, or
+    /// `None` if any of them would hit a wall.
+    fn vertical_push(
+        &self,
+        position: Point<usize>,
+        direction: Direction4,
+    ) -> Option<Vec<Point<usize>>> {
+        let mut boxes = Vec::new();
+        let mut seen = HashSet::new();
+        let mut queue = VecDeque::from([position]);
+
+        // Boxes can rest on two others, so the same box can be reached twice.
+        while let Some(position) = queue.pop_front() {
+            let left = match self.get_tile(position) {
+                Some(WideTile::Wall) => return None,
+                Some(WideTile::BoxLeft) => position,
+                Some(WideTile::BoxRight) => self.neighbour(position, Direction4::Left)?,
+                None => continue,
+            };
+
+            if seen.insert(left) {
+                let right = self.neighbour(left, Direction4::Right)?;
+                boxes.push(left);
+                queue.extend([
+                    self.neighbour(left, direction)?,
+                    self.neighbour(right, direction)?,
+                ]);
+            }
+        }
+
+        Some(boxes)
+    }
+
+    /// Moves boxes found by [`Self::vertical_push`], furthest first so each has space to move into.
+    fn push_vertical(&mut self, boxes: &[Point<usize>], direction: Direction4) {
+        for &left in boxes.iter().rev() {
+            for half in [left, Point::new(left.x + 1, left.y)] {
+                let to = self
+                    .neighbour(half, direction)
+                    .expect("Vertical pushes are checked against the edge");
+                self.push_tile(half, to);
+            }
+        }
+    }
+}
+
+impl Display for WideWarehouse {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.fmt_map(f)
+    }
+}
+
+impl Warehouse<WideTile> for WideWarehouse {
+    const RECORDING: &'static str = "wide-warehouse.gif";
+
+    fn split_box(&self) -> Option<Point<usize>> {
+        width_height_2d_iter(self.width, self.height)
+            .map(Point::from)
+            .find(|&position| match self.get_tile(position) {
+                Some(WideTile::BoxLeft) => !matches!(
+                    self.neighbour(position, Direction4::Right)
+                        .and_then(|other| self.get_tile(other)),
+                    Some(WideTile::BoxRight)
+                ),
+                Some(WideTile::BoxRight) => !matches!(
+                    self.neighbour(position, Direction4::Left)
+                        .and_then(|other| self.get_tile(other)),
+                    Some(WideTile::BoxLeft)
+                ),
+                _ => false,
+            })
+    }
+
+    fn push(&mut self, position: Point<usize>, direction: Direction4) -> bool {
+        if direction.is_vertical() {
+            let Some(boxes) = self.vertical_push(position, direction) else {
+                return false;
+            };
+
+            self.push_vertical(&boxes, direction);
+            return true;
+        }
+
+        let Some(push_position) = self.neighbour(position, direction) else {
+            return false;
+        };
+
+        let can_push = match self.get_tile(push_position) {
+            Some(WideTile::Wall) => false,
+            Some(WideTile::BoxLeft | WideTile::BoxRight) => self.push(push_position, direction),
+            None => true,
+        };
+
+        if can_push {
+            self.push_tile(position, push_position);
+        }
+
+        can_push
+    }
+
+    fn step(&mut self, direction: Direction4) {
+        let Some(new_position) = self.neighbour(self.robot, direction) else {
+            return;
+        };
+
+        let can_move = match self.get_tile(new_position) {
+            Some(WideTile::Wall) => false,
+            Some(WideTile::BoxRight | WideTile::BoxLeft) => self.push(new_position, direction),
+            None => true,
+        };
+
+        if can_move {
+            self.robot = new_position;
+        }
+    }
+
+    fn get_tiles(&self) -> &Vec<Vec<Option<WideTile>>> {
+        &self.tiles
+    }
+
+    fn get_tiles_mut(&mut self) -> &mut Vec<Vec<Option<WideTile>>> {
+        &mut self.tiles
+    }
+
+    fn get_directions(&self) -> &Vec<Direction4> {
+        &self.directions
+    }
+
+    fn get_width(&self) -> usize {
+        self.width
+    }
+
+    fn get_height(&self) -> usize {
+        self.height
+    }
+
+    fn get_robot(&self) -> Point<usize> {
+        self.robot
+    }
+}
+
+pub struct Day15;
+
+impl Solution for Day15 {
+    const YEAR: u16 = 2024;
+    const DAY: u8 = 15;
+
+    type Input = NormalWarehouse;
+
+    fn parse(raw: &str) -> PuzzleResult<Self::Input> {
+        NormalWarehouse::from_raw(raw)
+    }
+
+    fn part_one(input: &Self::Input) -> PuzzleResult<impl Display> {
+        Ok(input.clone().step_all())
+    }
+
+    fn part_two(input: &Self::Input) -> PuzzleResult<impl Display> {
+        Ok(input.clone().widen().step_all())
+    }
+}
+
+pub fn register(registry: &mut Registry) {
+    registry.register::<Day15>();
+}
+
+#[cfg(test)]
+mod tests {
+    use proptest::prelude::*;
+
+    use super::*;
+
+    const EXAMPLE_ONE: &str = "##########
+#..O..O.O#
+#......O.#
+#.OO..O.O#
+#..O@..O.#
+#O#..O...#
+#O..O..O.#
+#.OO.O.OO#
+#....O...#
+##########
+
+<vv>^<v^>v>^vv^v>v<>v^v<v<^vv<<<^><<><>>v<vvv<>^v^>^<<<><<v<<<v^vv^v>^
+vvv<<^>^v^^><<>>><>^<<><^vv^^<>vvv<>><^^v>^>vv<>v<<<<v<^v>^<^^>>>^<v<v
+><>vv>v^v^<>><>>>><^^>vv>v<^^^>>v^v^<^^>v^^>v^<^v>v<>>v^v^<v>v^^<^^vv<
+<<v<^>>^^^^>>>v^<>vvv^><v<<<>^^^vv^<vvv>^>v<^^^^v<>^>vvvv><>>v^<<^^^^^
+^><^><>>><>^^<<^^v>>><^<v>^<vv>>v>>>^v><>^v><<<<v>>v<v<v>vvv>^<><<>^><
+^>><>^v<><^vvv<^^<><v<<<<<><^v<<<><<<^^<v<^^^><^>>^<v^><<<^>>^v<v^v<v^
+>^>>^v>vv>^<<^v<>><<><<v<<v><>v<^vv<<<>^^v^>^^>>><<^v>>v^v><^^>>^<>vv^
+<><^^>^^^<><vvvvv^v<v<<>^v<v>v<<^><<><<><<<^^<<<^<<>><<><^^^>^^<>^>v<>
+^^>vv<^v^v<vv>^<><v<^v>^^^>>>^^vvv^>vvv<>>>^<^>>>>>^<<^v>^vvv<>^<><<v>
+v^^>>><<^^<>>^v^<v^vv<>v^<<>^<^v^v><^<<<><<^<v><v<>vv>>v><v^<vv<>v^<<^";
+
+    const EXAMPLE_TWO: &str = "########
+#..O.O.#
+##@.O..#
+#...O..#
+#.#.O..#
+#...O..#
+#......#
+########
+
+<^^>>>vv<v>>v<<";
+
+    const EXAMPLE_THREE: &str = "#######
+#...#.#
+#.....#
+#..OO@#
+#..O..#
+#.....#
+#######
+
+<vv<<^^<<^^";
+
+    /// The map after each move of [`EXAMPLE_TWO`], as drawn in the puzzle.
+    const EXAMPLE_TWO_STEPS: [&str; 16] = [
+        "########
+#..O.O.#
+##@.O..#
+#...O..#
+#.#.O..#
+#...O..#
+#......#
+########",
+        "########
+#..O.O.#
+##@.O..#
+#...O..#
+#.#.O..#
+#...O..#
+#......#
+########",
+        "########
+#.@O.O.#
+##..O..#
+#...O..#
+#.#.O..#
+#...O..#
+#......#
+########",
+        "########
+#.@O.O.#
+##..O..#
+#...O..#
+#.#.O..#
+#...O..#
+#......#
+########",
+        "########
+#..@OO.#
+##..O..#
+#...O..#
+#.#.O..#
+#...O..#
+#......#
+########",
+        "########
+#...@OO#
+##..O..#
+#...O..#
+#.#.O..#
+#...O..#
+#......#
+########",
+        "########
+#...@OO#
+##..O..#
+#...O..#
+#.#.O..#
+#...O..#
+#......#
+########",
+        "########
+#....OO#
+##..@..#
+#...O..#
+#.#.O..#
+#...O..#
+#...O..#
+########",
+        "########
+#....OO#
+##..@..#
+#...O..#
+#.#.O..#
+#...O..#
+#...O..#
+########",
+        "########
+#....OO#
+##.@...#
+#...O..#
+#.#.O..#
+#...O..#
+#...O..#
+########",
+        "########
+#....OO#
+##.....#
+#..@O..#
+#.#.O..#
+#...O..#
+#...O..#
+########",
+        "########
+#....OO#
+##.....#
+#...@O.#
+#.#.O..#
+#...O..#
+#...O..#
+########",
+        "########
+#....OO#
+##.....#
+#....@O#
+#.#.O..#
+#...O..#
+#...O..#
+########",
+        "########
+#....OO#
+##.....#
+#.....O#
+#.#.O@.#
+#...O..#
+#...O..#
+########",
+        "########
+#....OO#
+##.....#
+#.....O#
+#.#O@..#
+#...O..#
+#...O..#
+########",
+        "########
+#....OO#
+##.....#
+#.....O#
+#.#O@..#
+#...O..#
+#...O..#
+########",
+    ];
+
+    /// The map after each move of [`EXAMPLE_THREE`] once widened.
+    const EXAMPLE_THREE_STEPS: [&str; 12] = [
+        "##############
+##......##..##
+##..........##
+##....[][]@.##
+##....[]....##
+##..........##
+##############",
+        "##############
+##......##..##
+##..........##
+##...[][]@..##
+##....[]....##
+##..........##
+##############",
+        "##############
+##......##..##
+##..........##
+##...[][]...##
+##....[].@..##
+##..........##
+##############",
+        "##############
+##......##..##
+##..........##
+##...[][]...##
+##....[]....##
+##.......@..##
+##############",
+        "##############
+##......##..##
+##..........##
+##...[][]...##
+##....[]....##
+##......@...##
+##############",
+        "##############
+##......##..##
+##..........##
+##...[][]...##
+##....[]....##
+##.....@....##
+##############",
+        "##############
+##......##..##
+##...[][]...##
+##....[]....##
+##.....@....##
+##..........##
+##############",
+        "##############
+##......##..##
+##...[][]...##
+##....[]....##
+##.....@....##
+##..........##
+##############",
+        "##############
+##......##..##
+##...[][]...##
+##....[]....##
+##....@.....##
+##..........##
+##############",
+        "##############
+##......##..##
+##...[][]...##
+##....[]....##
+##...@......##
+##..........##
+##############",
+        "##############
+##......##..##
+##...[][]...##
+##...@[]....##
+##..........##
+##..........##
+##############",
+        "##############
+##...[].##..##
+##...@.[]...##
+##....[]....##
+##..........##
+##..........##
+##############",
+    ];
+
+    #[test]
+    fn example_1() {
+        let mut warehouse = NormalWarehouse::from_raw(EXAMPLE_ONE).unwrap();
+        assert_eq!(warehouse.step_all(), 10092);
+    }
+
+    #[test]
+    fn example_2() {
+        let mut warehouse = NormalWarehouse::from_raw(EXAMPLE_TWO).unwrap();
+        assert_eq!(warehouse.step_all(), 2028);
+    }
+
+    #[test]
+    fn example_3() {
+        let mut warehouse = NormalWarehouse::from_raw(EXAMPLE_ONE).unwrap().widen();
+        assert_eq!(warehouse.step_all(), 9021);
+    }
+
+    fn assert_moves(map: &str, moves: &str, expected: &str) {
+        let mut warehouse = WideWarehouse::from_raw(&format!("{}\n\n{}", map, moves)).unwrap();
+        warehouse.step_all();
+        assert_eq!(warehouse.to_string(), expected);
+    }
+
+    #[test]
+    fn widen() {
+        let warehouse = NormalWarehouse::from_raw(EXAMPLE_TWO).unwrap().widen();
+        assert_eq!(
+            warehouse.to_string(),
+            "################
+##....[]..[]..##
+####@...[]....##
+##......[]....##
+##..##..[]....##
+##......[]....##
+##............##
+################"
+        );
+    }
+
+    #[test]
+    fn push_diamond() {
+        assert_moves(
+            "##########
+#........#
+#...[]...#
+#..[][]..#
+#...[]...#
+#....@...#
+##########",
+            "^",
+            "##########
+#...[]...#
+#..[][]..#
+#...[]...#
+#....@...#
+#........#
+##########",
+        );
+    }
+
+    #[test]
+    fn push_diamond_down() {
+        assert_moves(
+            "##########
+#...@....#
+#...[]...#
+#..[][]..#
+#...[]...#
+#........#
+##########",
+            "v",
+            "##########
+#........#
+#...@....#
+#...[]...#
+#..[][]..#
+#...[]...#
+##########",
+        );
+    }
+
+    #[test]
+    fn push_blocked_branch() {
+        // Only the top right box is blocked, but nothing can move without it.
+        let map = "##########
+#......#.#
+#..[][][]#
+#...[][].#
+#....[]..#
+#....@...#
+##########";
+
+        assert_moves(map, "^", map);
+    }
+
+    #[test]
+    fn push_wall_under_half() {
+        let left = "########
+#...#..#
+#...[].#
+#....@.#
+########";
+        let right = "########
+#....#.#
+#...[].#
+#...@..#
+########";
+
+        assert_moves(left, "^", left);
+        assert_moves(right, "^", right);
+    }
+
+    #[test]
+    fn push_staircase() {
+        assert_moves(
+            "##########
+#........#
+#.[].....#
+#..[]....#
+#...[]...#
+#....@...#
+##########",
+            "^>^",
+            "##########
+#.[].....#
+#..[]....#
+#...[]@..#
+#........#
+#........#
+##########",
+        );
+    }
+
+    /// Checks the map before any moves and after each one.
+    fn assert_steps<T: IsBox>(mut warehouse: impl Warehouse<T> + Display, states: &[&str]) {
+        let directions = warehouse.get_directions().clone();
+        assert_eq!(directions.len() + 1, states.len());
+        assert_eq!(warehouse.to_string(), states[0]);
+
+        for (index, (direction, state)) in directions.into_iter().zip(&states[1..]).enumerate() {
+            warehouse.step(direction);
+            assert_eq!(
+                warehouse.to_string(),
+                *state,
+                "after move {} ({})",
+                index + 1,
+                direction
+            );
+        }
+    }
+
+    #[test]
+    fn steps() {
+        assert_steps(
+            NormalWarehouse::from_raw(EXAMPLE_TWO).unwrap(),
+            &EXAMPLE_TWO_STEPS,
+        );
+    }
+
+    #[test]
+    fn wide_steps() {
+        let warehouse = NormalWarehouse::from_raw(EXAMPLE_THREE).unwrap().widen();
+        assert_steps(warehouse, &EXAMPLE_THREE_STEPS);
+    }
+
+    #[test]
+    fn round_trip() {
+        let normal = NormalWarehouse::from_raw(EXAMPLE_ONE).unwrap();
+        let parsed = NormalWarehouse::from_raw(&normal.to_string()).unwrap();
+        assert_eq!(parsed.to_string(), normal.to_string());
+
+        let wide = normal.widen();
+        let mut parsed = WideWarehouse::from_raw(&format!("{}\n\n{}", wide, moves(&wide))).unwrap();
+        assert_eq!(parsed.to_string(), wide.to_string());
+        assert_eq!(parsed.step_all(), 9021);
+    }
+
+    fn moves<T: IsBox>(warehouse: &impl Warehouse<T>) -> String {
+        warehouse
+            .get_directions()
+            .iter()
+            .map(|direction| direction.to_char())
+            .collect()
+    }
+
+    #[test]
+    fn invariants() {
+        let mut warehouse = WideWarehouse::from_raw(
+            "########
+#.[]...#
+#..@[].#
+########",
+        )
+        .unwrap();
+        assert_eq!(warehouse.check_invariants(2), Ok(()));
+        assert_eq!(
+            warehouse.check_invariants(3),
+            Err(InvariantError::BoxCount {
+                expected: 3,
+                found: 2
+            })
+        );
+
+        warehouse.tiles[2][5] = None;
+        assert_eq!(
+            warehouse.check_invariants(2),
+            Err(InvariantError::SplitBox(Point::new(4, 2)))
+        );
+
+        warehouse.tiles[2][4] = None;
+        warehouse.robot = Point::new(2, 1);
+        assert_eq!(
+            warehouse.check_invariants(1),
+            Err(InvariantError::RobotOnTile(Point::new(2, 1)))
+        );
+    }
+
+    /// A walled map `width` by `height` inside, built from `tiles` choices, with the robot at
+    /// the `robot`th cell. Wide maps place whole boxes wherever they fit.
+    fn generate(width: usize, height: usize, tiles: &[u8], robot: usize, wide: bool) -> String {
+        let mut tiles = tiles.iter();
+        let mut rows = (0..height)
+            .map(|_| {
+                let mut row = Vec::new();
+
+                while row.len() < width {
+                    match tiles.next().unwrap_or(&0) {
+                        2 => row.push('#'),
+                        3 if !wide => row.push('O'),
+                        3 if row.len() + 1 < width => row.extend(['[', ']']),
+                        _ => row.push('.'),
+                    }
+                }
+
+                row
+            })
+            .collect::<Vec<_>>();
+
+        let (x, y) = (robot % width, robot / width % height);
+        let row = &mut rows[y];
+
+        match row[x] {
+            '[' => row[x + 1] = '.',
+            ']' => row[x - 1] = '.',
+            _ => (),
+        }
+
+        row[x] = '@';
+
+        let wall = "#".repeat(width + 2);
+        let rows = rows
+            .into_iter()
+            .map(|row| format!("#{}#", row.into_iter().collect::<String>()));
+
+        [wall.clone()]
+            .into_iter()
+            .chain(rows)
+            .chain([wall])
+            .collect::<Vec<_>>()
+            .join("\n")
+    }
+
+    fn assert_invariants<T: IsBox>(
+        mut warehouse: impl Warehouse<T>,
+        moves: &[Direction4],
+    ) -> Result<(), TestCaseError> {
+        let boxes = warehouse.box_count();
+        prop_assert_eq!(warehouse.check_invariants(boxes), Ok(()));
+
+        for (index, &direction) in moves.iter().enumerate() {
+            warehouse.step(direction);
+            prop_assert_eq!(
+                warehouse.check_invariants(boxes),
+                Ok(()),
+                "after move {} ({})",
+                index + 1,
+                direction
+            );
+        }
+
+        Ok(())
+    }
+
+    fn direction() -> impl Strategy<Value = Direction4> {
+        prop_oneof![
+            Just(Direction4::Up),
+            Just(Direction4::Right),
+            Just(Direction4::Down),
+            Just(Direction4::Left),
+        ]
+    }
+
+    proptest! {
+        #[test]
+        fn fuzz_normal(
+            width in 1..12usize,
+            height in 1..12usize,
+            tiles in prop::collection::vec(0..4u8, 144),
+            robot in 0..144usize,
+            moves in prop::collection::vec(direction(), 0..200),
+        ) {
+            let map = generate(width, height, &tiles, robot, false);
+            let warehouse = NormalWarehouse::from_raw(&map).unwrap();
+
+            assert_invariants(warehouse.clone(), &moves)?;
+            assert_invariants(warehouse.widen(), &moves)?;
+        }
+
+        #[test]
+        fn fuzz_wide(
+            width in 2..24usize,
+            height in 1..12usize,
+            tiles in prop::collection::vec(0..4u8, 288),
+            robot in 0..288usize,
+            moves in prop::collection::vec(direction(), 0..200),
+        ) {
+            let map = generate(width, height, &tiles, robot, true);
+            assert_invariants(WideWarehouse::from_raw(&map).unwrap(), &moves)?;
+        }
+    }
+
+    #[test]
+    fn unwalled_edges() {
+        let mut warehouse = NormalWarehouse::from_raw("@O.\n...\n\n<^>>>").unwrap();
+        warehouse.step_all();
+        assert_eq!(warehouse.to_string(), ".@O\n...");
+
+        let mut warehouse = WideWarehouse::from_raw("@[]\n...\n\n^>v").unwrap();
+        warehouse.step_all();
+        assert_eq!(warehouse.to_string(), ".[]\n@..");
+
+        let mut warehouse = WideWarehouse::from_raw(".@.\n.[]\n\nv").unwrap();
+        warehouse.step_all();
+        assert_eq!(warehouse.to_string(), ".@.\n.[]");
+    }
+
+    /// A xorshift generator, so the stress test is the same every run.
+    fn random(state: &mut u64) -> u64 {
+        *state ^= *state << 13;
+        *state ^= *state >> 7;
+        *state ^= *state << 17;
+        *state
+    }
+
+    fn assert_stress<T: IsBox>(mut warehouse: impl Warehouse<T>, moves: &[Direction4]) {
+        let boxes = warehouse.box_count();
+
+        for &direction in moves {
+            warehouse.step(direction);
+        }
+
+        assert_eq!(warehouse.check_invariants(boxes), Ok(()));
+        assert!(warehouse.box_gps_sum() > u32::MAX as usize);
+    }
+
+    #[test]
+    fn stress() {
+        const SIZE: usize = 998;
+
+        let mut state = 0x2024_0015;
+        let tiles = (0..SIZE * SIZE)
+            .map(|_| match random(&mut state) % 20 {
+                0 => 2,
+                1..=6 => 3,
+                _ => 0,
+            })
+            .collect::<Vec<_>>();
+        let moves = (0..20_000)
+            .map(|_| Direction4::ALL[random(&mut state) as usize % 4])
+            .collect::<Vec<_>>();
+
+        let map = generate(SIZE, SIZE, &tiles, SIZE * SIZE / 2 + SIZE / 2, false);
+        let warehouse = NormalWarehouse::from_raw(&map).unwrap();
+        assert_eq!((warehouse.width, warehouse.height), (1000, 1000));
+        assert_eq!(warehouse.robot, Point::new(500, 500));
+
+        assert_stress(warehouse.clone(), &moves);
+        assert_stress(warehouse.widen(), &moves);
+    }
+
+    #[test]
+    fn parse_errors() {
+        let error = Day15::parse("#...\n#...\n\n<").unwrap_err();
+        assert_eq!(error, PuzzleError::parse(1, 1, "no robot '@' on the map"));
+
+        let error = Day15::parse("#.@.\n#.@.\n\n<").unwrap_err();
+        assert_eq!(error, PuzzleError::parse(2, 3, "a second robot"));
+
+        let error = Day15::parse("#.@.\n#.X.\n\n<").unwrap_err();
+        assert_eq!(error, PuzzleError::parse(2, 3, "unexpected 'X'"));
+
+        let error = Day15::parse("#.@.\n#.\n\n<").unwrap_err();
+        assert_eq!(error, PuzzleError::parse(2, 3, "row is 2 wide, expected 4"));
+
+        let error = Day15::parse("").unwrap_err();
+        assert_eq!(error, PuzzleError::parse(1, 1, "the map is empty"));
+
+        let error = Day15::parse("\n\n<").unwrap_err();
+        assert_eq!(error, PuzzleError::parse(1, 1, "the map is empty"));
+
+        let error = WideWarehouse::from_raw("#.@.\n#.O.\n\n<").unwrap_err();
+        assert_eq!(error, PuzzleError::parse(2, 3, "unexpected 'O'"));
+
+        let error = WideWarehouse::from_raw("#[..\n#.@.\n\n^").unwrap_err();
+        assert_eq!(error, PuzzleError::parse(1, 2, "half a box"));
+
+        let error = WideWarehouse::from_raw("#.@.\n.][.\n\nv").unwrap_err();
+        assert_eq!(error, PuzzleError::parse(2, 2, "half a box"));
+
+        let error = Day15::parse("#.@.\n#...\n\n<^\n>v x").unwrap_err();
+        assert_eq!(
+            error,
+            PuzzleError::parse(5, 3, "expected a move, found ' '")
+        );
+
+        let error = Day15::parse("#.@.\n#...\n\nhello world <").unwrap_err();
+        assert_eq!(
+            error,
+            PuzzleError::parse(4, 1, "expected a move, found 'h'")
+        );
+    }
+}
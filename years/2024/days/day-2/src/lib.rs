@@ -0,0 +1,118 @@
+use std::{cmp::Ordering, fmt::Display};
+
+use common::{parse::*, *};
+
+fn parse_reports(data: &str) -> Result<Vec<Vec<u8>>, ParseError> {
+    parse_all(data, lines(separated(unsigned(), literal(" "))))
+}
+
+fn is_level_safe(level: u8, next_level: u8, report_delta: Ordering) -> bool {
+    let delta = level.cmp(&next_level);
+
+    // Change in direction
+    if delta != report_delta {
+        return false;
+    }
+
+    let distance = level.abs_diff(next_level);
+
+    matches!(distance, 1..=3)
+}
+
+fn is_report_safe(report: &[u8]) -> bool {
+    // A single level can't change direction.
+    let [first, second, ..] = report else {
+        return true;
+    };
+
+    let report_delta = first.cmp(second);
+
+    for i in 0..(report.len() - 1) {
+        let level = report[i];
+        let next_level = report[i + 1];
+
+        if !is_level_safe(level, next_level, report_delta) {
+            return false;
+        }
+    }
+
+    true
+}
+
+fn is_report_mostly_safe(report: &[u8]) -> bool {
+    if is_report_safe(report) {
+        true
+    } else {
+        for i in 0..report.len() {
+            let mut report = report.to_vec();
+            report.remove(i);
+            if is_report_safe(&report) {
+                return true;
+            }
+        }
+
+        false
+    }
+}
+
+fn part_one(reports: &[Vec<u8>]) -> usize {
+    reports
+        .iter()
+        .filter(|report| is_report_safe(report))
+        .count()
+}
+
+fn part_two(reports: &[Vec<u8>]) -> usize {
+    reports
+        .iter()
+        .filter(|report| is_report_mostly_safe(report))
+        .count()
+}
+
+pub struct Day2;
+
+impl Solution for Day2 {
+    const YEAR: u16 = 2024;
+    const DAY: u8 = 2;
+
+    type Input = Vec<Vec<u8>>;
+
+    fn parse(raw: &str) -> PuzzleResult<Self::Input> {
+        Ok(parse_reports(raw)?)
+    }
+
+    fn part_one(input: &Self::Input) -> PuzzleResult<impl Display> {
+        Ok(part_one(input))
+    }
+
+    fn part_two(input: &Self::Input) -> PuzzleResult<impl Display> {
+        Ok(part_two(input))
+    }
+}
+
+pub fn register(registry: &mut Registry) {
+    registry.register::<Day2>();
+}
+
+example_tests!(Day2);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parse_error() {
+        let error = Day2::parse("7 6 4\n1 2 a").unwrap_err();
+        assert_eq!(
+            error,
+            PuzzleError::parse(2, 5, "expected a digit, found 'a'")
+        );
+    }
+
+    #[test]
+    fn short_reports() {
+        let reports = Day2::parse("1 9\n4").unwrap();
+        assert_eq!(part_one(&reports), 1);
+        assert_eq!(part_two(&reports), 2);
+    }
+}
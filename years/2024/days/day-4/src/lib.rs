@@ -0,0 +1,228 @@
+use std::fmt::Display;
+
+use common::*;
+
+#[derive(Debug)]
+pub struct WordTable {
+    table: Grid<char>,
+    width: usize,
+    height: usize,
+}
+
+impl WordTable {
+    fn new(raw: &str) -> PuzzleResult<Self> {
+        let table = Grid::from_str_with(raw, |character| character)?;
+
+        if table.width() == 0 {
+            return Err(PuzzleError::parse(1, 1, "the word search is empty"));
+        }
+
+        Ok(Self {
+            width: table.width(),
+            height: table.height(),
+            table,
+        })
+    }
+
+    fn scan_xmas(&self) -> u32 {
+        let search_value = "MAS";
+        let search_length = search_value.len();
+
+        width_height_2d_iter(self.width, self.height)
+            .filter(|(x, y)| self.get_char(*x, *y) == 'X')
+            .map(|(x, y)| {
+                let mut found = 0;
+                let allowed_up = y >= search_length;
+                let allowed_left = x >= search_length;
+                let allowed_down = y + search_length < self.height;
+                let allowed_right = x + search_length < self.width;
+
+                if allowed_down && self.scan(x, y, search_value, |_, y| *y += 1) {
+                    found += 1;
+                }
+
+                if allowed_up && self.scan(x, y, search_value, |_, y| *y -= 1) {
+                    found += 1;
+                }
+
+                if allowed_left && self.scan(x, y, search_value, |x, _| *x -= 1) {
+                    found += 1;
+                }
+
+                if allowed_right && self.scan(x, y, search_value, |x, _| *x += 1) {
+                    found += 1;
+                }
+
+                if allowed_right
+                    && allowed_down
+                    && self.scan(x, y, search_value, |x, y| {
+                        *x += 1;
+                        *y += 1
+                    })
+                {
+                    found += 1;
+                }
+
+                if allowed_left
+                    && allowed_up
+                    && self.scan(x, y, search_value, |x, y| {
+                        *x -= 1;
+                        *y -= 1
+                    })
+                {
+                    found += 1;
+                }
+
+                if allowed_left
+                    && allowed_down
+                    && self.scan(x, y, search_value, |x, y| {
+                        *x -= 1;
+                        *y += 1
+                    })
+                {
+                    found += 1;
+                }
+
+                if allowed_right
+                    && allowed_up
+                    && self.scan(x, y, search_value, |x, y| {
+                        *x += 1;
+                        *y -= 1
+                    })
+                {
+                    found += 1;
+                }
+
+                found
+            })
+            .sum()
+    }
+
+    fn scan_x_mas(&self) -> u32 {
+        width_height_start_2d_iter(
+            1,
+            1,
+            self.width.saturating_sub(1),
+            self.height.saturating_sub(1),
+        )
+        .filter(|(x, y)| self.get_char(*x, *y) == 'A')
+        .filter(|(x, y)| {
+            let top_left = self.get_char(x - 1, y - 1);
+            let top_right = self.get_char(x + 1, y - 1);
+            let bottom_left = self.get_char(x - 1, y + 1);
+            let bottom_right = self.get_char(x + 1, y + 1);
+
+            let top_left_mas = top_left == 'M' && bottom_right == 'S';
+            let bottom_right_mas = top_left == 'S' && bottom_right == 'M';
+            let top_right_mas = top_right == 'M' && bottom_left == 'S';
+            let bottom_left_mas = top_right == 'S' && bottom_left == 'M';
+
+            (top_left_mas || bottom_right_mas) && (top_right_mas || bottom_left_mas)
+        })
+        .count() as u32
+    }
+
+    fn get_char(&self, x: usize, y: usize) -> char {
+        self.table[(x, y)]
+    }
+
+    fn scan(
+        &self,
+        mut x: usize,
+        mut y: usize,
+        value: &str,
+        operation: impl Fn(&mut usize, &mut usize),
+    ) -> bool {
+        for value_char in value.chars() {
+            operation(&mut x, &mut y);
+            if self.get_char(x, y) != value_char {
+                return false;
+            }
+        }
+
+        true
+    }
+}
+
+pub struct Day4;
+
+impl Solution for Day4 {
+    const YEAR: u16 = 2024;
+    const DAY: u8 = 4;
+
+    type Input = WordTable;
+
+    fn parse(raw: &str) -> PuzzleResult<Self::Input> {
+        WordTable::new(raw)
+    }
+
+    fn part_one(input: &Self::Input) -> PuzzleResult<impl Display> {
+        Ok(input.scan_xmas())
+    }
+
+    fn part_two(input: &Self::Input) -> PuzzleResult<impl Display> {
+        Ok(input.scan_x_mas())
+    }
+}
+
+pub fn register(registry: &mut Registry) {
+    registry.register::<Day4>();
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE_ONE: &str = "MMMSXXMASM
+MSAMXMSMSA
+AMXSXMAAMM
+MSAMASMSMX
+XMASAMXAMM
+XXAMMXXAMA
+SMSMSASXSS
+SAXAMASAAA
+MAMMMXMMMM
+MXMXAXMASX";
+
+    const EXAMPLE_TWO: &str = ".M.S......
+..A..MSMS.
+.M.S.MAA..
+..A.ASMSM.
+.M.S.M....
+..........
+S.S.S.S.S.
+.A.A.A.A..
+M.M.M.M.M.
+..........";
+
+    #[test]
+    fn example_1() {
+        let table = WordTable::new(EXAMPLE_ONE).unwrap();
+        assert_eq!(table.scan_xmas(), 18);
+    }
+
+    #[test]
+    fn example_2() {
+        let table = WordTable::new(EXAMPLE_TWO).unwrap();
+        assert_eq!(table.scan_x_mas(), 9);
+    }
+
+    #[test]
+    fn parse_error() {
+        let error = Day4::parse("XMAS\nXMA").unwrap_err();
+        assert_eq!(error, PuzzleError::parse(2, 4, "row is 3 wide, expected 4"));
+
+        let error = Day4::parse("").unwrap_err();
+        assert_eq!(error, PuzzleError::parse(1, 1, "the word search is empty"));
+    }
+
+    #[test]
+    fn small() {
+        let table = WordTable::new("X").unwrap();
+        assert_eq!(table.scan_xmas(), 0);
+        assert_eq!(table.scan_x_mas(), 0);
+
+        let table = WordTable::new("XMAS").unwrap();
+        assert_eq!(table.scan_xmas(), 1);
+    }
+}
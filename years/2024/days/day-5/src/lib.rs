@@ -0,0 +1,235 @@
+use std::{collections::HashMap, fmt::Display};
+
+use common::{parse::*, *};
+use itertools::Itertools;
+
+type Table = HashMap<u8, Vec<u8>>;
+
+fn orders_parser<'a>() -> impl Parser<'a, Table> {
+    map(
+        lines(separated_pair(unsigned(), literal("|"), unsigned())),
+        |orders| {
+            let mut after_table = HashMap::<u8, Vec<u8>>::new();
+
+            for (before, after) in orders {
+                after_table.entry(before).or_default().push(after);
+            }
+
+            after_table
+        },
+    )
+}
+
+fn parse_input(raw: &str) -> Result<(Table, Vec<Vec<u8>>), ParseError> {
+    parse_all(
+        raw,
+        separated_pair(
+            orders_parser(),
+            literal("\n\n"),
+            lines(separated(unsigned(), literal(","))),
+        ),
+    )
+}
+
+fn find_issue(after_table: &Table, page: &[u8]) -> Option<(usize, usize)> {
+    for pages in page.iter().enumerate().combinations(2) {
+        let (page_a_index, page_a) = pages[0];
+        let (page_b_index, page_b) = pages[1];
+
+        if after_table
+            .get(page_b)
+            .map(|values| values.contains(page_a))
+            .unwrap_or_default()
+        {
+            return Some((page_a_index, page_b_index));
+        }
+    }
+
+    None
+}
+
+fn middle_check(after_table: &Table, page: &[u8]) -> Option<u8> {
+    if find_issue(after_table, page).is_some() {
+        None
+    } else {
+        Some(page[page.len() / 2])
+    }
+}
+
+fn reorder(after_table: &Table, mut page: Vec<u8>) -> Vec<u8> {
+    while let Some((index_a, index_b)) = find_issue(after_table, &page) {
+        page.swap(index_a, index_b);
+    }
+
+    page
+}
+
+fn part_one(after_table: &Table, pages: &[Vec<u8>]) -> u32 {
+    pages
+        .iter()
+        .filter_map(|page| middle_check(after_table, page))
+        .map(u32::from)
+        .sum()
+}
+
+fn part_two(after_table: &Table, pages: Vec<Vec<u8>>) -> u32 {
+    pages
+        .into_iter()
+        .filter(|page| find_issue(after_table, page).is_some())
+        .map(|page| reorder(after_table, page))
+        .map(|page| page[page.len() / 2])
+        .map(u32::from)
+        .sum()
+}
+
+pub struct Day5;
+
+impl Solution for Day5 {
+    const YEAR: u16 = 2024;
+    const DAY: u8 = 5;
+
+    type Input = (Table, Vec<Vec<u8>>);
+
+    fn parse(raw: &str) -> PuzzleResult<Self::Input> {
+        Ok(parse_input(raw)?)
+    }
+
+    fn part_one(input: &Self::Input) -> PuzzleResult<impl Display> {
+        let (after_table, pages) = input;
+
+        Ok(part_one(after_table, pages))
+    }
+
+    fn part_two(input: &Self::Input) -> PuzzleResult<impl Display> {
+        let (after_table, pages) = input;
+
+        Ok(part_two(after_table, pages.clone()))
+    }
+}
+
+pub fn register(registry: &mut Registry) {
+    registry.register::<Day5>();
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn parse_orders(orders: &str) -> Table {
+        parse_all(orders, orders_parser()).unwrap()
+    }
+
+    const EXAMPLE_ONE_ORDERS: &str = "47|53
+97|13
+97|61
+97|47
+75|29
+61|13
+75|53
+29|13
+97|29
+53|29
+61|53
+97|53
+61|29
+47|13
+75|47
+97|75
+47|61
+75|61
+47|29
+75|13
+53|13";
+
+    const EXAMPLE_ONE: &str = "47|53
+97|13
+97|61
+97|47
+75|29
+61|13
+75|53
+29|13
+97|29
+53|29
+61|53
+97|53
+61|29
+47|13
+75|47
+97|75
+47|61
+75|61
+47|29
+75|13
+53|13
+
+75,47,61,53,29
+97,61,53,29,13
+75,29,13
+75,97,47,61,53
+61,13,29
+97,13,75,29,47";
+
+    #[test]
+    fn example_1_0() {
+        let after_table = parse_orders(EXAMPLE_ONE_ORDERS);
+        let page = &[75, 47, 61, 53, 29];
+        assert_eq!(middle_check(&after_table, page), Some(61));
+    }
+
+    #[test]
+    fn example_1_1() {
+        let after_table = parse_orders(EXAMPLE_ONE_ORDERS);
+        let page = &[97, 61, 53, 29, 13];
+        assert_eq!(middle_check(&after_table, page), Some(53));
+    }
+
+    #[test]
+    fn example_1_2() {
+        let after_table = parse_orders(EXAMPLE_ONE_ORDERS);
+        let page = &[75, 29, 13];
+        assert_eq!(middle_check(&after_table, page), Some(29));
+    }
+
+    #[test]
+    fn example_1_3() {
+        let after_table = parse_orders(EXAMPLE_ONE_ORDERS);
+        let page = &[75, 97, 47, 61, 43];
+        assert_eq!(middle_check(&after_table, page), None);
+    }
+
+    #[test]
+    fn example_1_4() {
+        let after_table = parse_orders(EXAMPLE_ONE_ORDERS);
+        let page = &[61, 13, 29];
+        assert_eq!(middle_check(&after_table, page), None);
+    }
+
+    #[test]
+    fn example_1_5() {
+        let after_table = parse_orders(EXAMPLE_ONE_ORDERS);
+        let page = &[97, 13, 75, 29, 47];
+        assert_eq!(middle_check(&after_table, page), None);
+    }
+
+    #[test]
+    fn example_1() {
+        let (after_table, pages) = parse_input(EXAMPLE_ONE).unwrap();
+        assert_eq!(part_one(&after_table, &pages), 143);
+    }
+
+    #[test]
+    fn parse_error() {
+        let error = Day5::parse("47|53\n97-13\n\n75,47,61").unwrap_err();
+        assert_eq!(
+            error,
+            PuzzleError::parse(2, 3, "expected \"|\", found '-13'")
+        );
+
+        let error = Day5::parse("47|53\n\n75,47,\n61").unwrap_err();
+        assert_eq!(
+            error,
+            PuzzleError::parse(3, 7, "expected a digit, found end of line")
+        );
+    }
+}
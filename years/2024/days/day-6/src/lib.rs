@@ -0,0 +1,218 @@
+use std::{
+    collections::HashSet, fmt::Display, iter::successors, sync::atomic::AtomicU32, time::Duration,
+};
+
+use common::{
+    image::{Image, Recording},
+    viz::{self, Cell, Colour, Frame},
+    *,
+};
+
+// If you see rayon you know this ain't good code
+use rayon::prelude::*;
+
+#[derive(Debug, Clone)]
+pub struct LabMap {
+    obstructions: HashSet<Point<u8>>,
+    guard_position: Point<u8>,
+    guard_direction: Direction4,
+    width: u8,
+    height: u8,
+}
+
+type Guard = (Point<u8>, Direction4);
+
+impl LabMap {
+    fn new(raw: &str) -> PuzzleResult<Self> {
+        let map = Grid::try_from_str_with(raw, |place| {
+            matches!(place, '.' | '#' | '^').then_some(place)
+        })?;
+
+        if map.width() > u8::MAX as usize || map.height() > u8::MAX as usize {
+            return Err(PuzzleError::parse(1, 1, "the lab is too big to map"));
+        }
+
+        let mut guard_position = None;
+        let mut obstructions = HashSet::new();
+
+        for ((x, y), &place) in map.iter() {
+            let point = Point::new(x as u8, y as u8);
+
+            match place {
+                '#' => {
+                    obstructions.insert(point);
+                }
+                '^' if guard_position.is_some() => {
+                    return Err(PuzzleError::parse(y + 1, x + 1, "a second guard"));
+                }
+                '^' => guard_position = Some(point),
+                _ => (),
+            }
+        }
+
+        let guard_position =
+            guard_position.ok_or_else(|| PuzzleError::parse(1, 1, "no guard '^' in the lab"))?;
+
+        Ok(Self {
+            obstructions,
+            guard_position,
+            guard_direction: Direction4::Up,
+            width: map.width() as u8,
+            height: map.height() as u8,
+        })
+    }
+
+    fn check_obstruction(&self, position: &Point<u8>) -> bool {
+        self.obstructions.contains(position)
+    }
+
+    /// Where the guard is and which way they're facing after one move, or `None` once they've
+    /// left the lab.
+    fn step(&self, (position, direction): Guard) -> Option<Guard> {
+        let new_position = position.bounded_step(direction, self.width, self.height)?;
+
+        // If surounded, will turn on the spot forever
+        if self.check_obstruction(&new_position) {
+            Some((position, direction.rotate_right()))
+        } else {
+            Some((new_position, direction))
+        }
+    }
+
+    fn guard(&self) -> Guard {
+        (self.guard_position, self.guard_direction)
+    }
+
+    /// The guard's state never repeats unless they're stuck in a loop, as moves are deterministic.
+    fn is_loop(&self) -> bool {
+        brent(self.guard(), |&guard| self.step(guard)).is_some()
+    }
+
+    fn frame(&self, visited: &HashSet<Point<u8>>, (position, direction): Guard) -> Frame {
+        let mut frame = Frame::new(self.width as usize, self.height as usize);
+
+        for (x, y) in width_height_2d_iter(self.width, self.height) {
+            let point = Point::new(x, y);
+            let cell = if point == position {
+                Cell::new(direction.to_char(), Colour::Red)
+            } else if self.check_obstruction(&point) {
+                Cell::new('#', Colour::White)
+            } else if visited.contains(&point) {
+                Cell::new('X', Colour::Yellow)
+            } else {
+                Cell::new('.', Colour::Grey)
+            };
+
+            frame.set((x as usize, y as usize), cell);
+        }
+
+        frame.with_caption(format!("{} visited", visited.len()))
+    }
+
+    fn part_one(&self) -> u32 {
+        let mut visited = HashSet::new();
+        let mut recording = Recording::new("patrol.gif", Duration::from_millis(20));
+
+        for guard in successors(Some(self.guard()), |&guard| self.step(guard)) {
+            visited.insert(guard.0);
+            viz::show(|| self.frame(&visited, guard));
+            recording.record(|| Image::from_frame(&self.frame(&visited, guard)).scaled(2));
+        }
+
+        recording.save();
+        visited.len() as u32
+    }
+
+    fn part_two(&self) -> u32 {
+        let mut positions = AtomicU32::new(0);
+
+        width_height_2d_iter(self.width, self.height)
+            .par_bridge()
+            .map(Point::from)
+            .for_each(|position| {
+                if !self.obstructions.contains(&position) && self.guard_position != position {
+                    let mut map_clone = self.clone();
+                    map_clone.obstructions.insert(position);
+                    if map_clone.is_loop() {
+                        positions.fetch_add(1, std::sync::atomic::Ordering::Relaxed);
+                    }
+                }
+            });
+
+        *positions.get_mut()
+    }
+}
+
+pub struct Day6;
+
+impl Solution for Day6 {
+    const YEAR: u16 = 2024;
+    const DAY: u8 = 6;
+
+    type Input = LabMap;
+
+    fn parse(raw: &str) -> PuzzleResult<Self::Input> {
+        LabMap::new(raw)
+    }
+
+    fn part_one(input: &Self::Input) -> PuzzleResult<impl Display> {
+        Ok(input.part_one())
+    }
+
+    fn part_two(input: &Self::Input) -> PuzzleResult<impl Display> {
+        Ok(input.part_two())
+    }
+}
+
+pub fn register(registry: &mut Registry) {
+    registry.register::<Day6>();
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE_ONE: &str = "....#.....
+.........#
+..........
+..#.......
+.......#..
+..........
+.#..^.....
+........#.
+#.........
+......#...";
+
+    #[test]
+    fn example_1() {
+        let map = LabMap::new(EXAMPLE_ONE).unwrap();
+        assert_eq!(map.part_one(), 41);
+    }
+
+    #[test]
+    fn example_2() {
+        let map = LabMap::new(EXAMPLE_ONE).unwrap();
+        assert_eq!(map.part_two(), 6);
+    }
+
+    #[test]
+    fn surrounded() {
+        let map = LabMap::new(".#.\n#^#\n.#.").unwrap();
+        assert!(map.is_loop());
+    }
+
+    #[test]
+    fn parse_error() {
+        let error = Day6::parse("..#\n.^^").unwrap_err();
+        assert_eq!(error, PuzzleError::parse(2, 3, "a second guard"));
+
+        let error = Day6::parse("..#\n...").unwrap_err();
+        assert_eq!(error, PuzzleError::parse(1, 1, "no guard '^' in the lab"));
+
+        let error = Day6::parse("..#\n.^>").unwrap_err();
+        assert_eq!(error, PuzzleError::parse(2, 3, "unexpected '>'"));
+
+        let error = Day6::parse("..#\n.^").unwrap_err();
+        assert_eq!(error, PuzzleError::parse(2, 3, "row is 2 wide, expected 3"));
+    }
+}
@@ -0,0 +1,205 @@
+use std::{
+    collections::{HashMap, HashSet},
+    fmt::Display,
+};
+
+use common::*;
+use itertools::Itertools;
+
+fn reduce_slope(slope: (isize, isize)) -> (isize, isize) {
+    let (x, y) = slope;
+
+    match gcd(x, y) {
+        0 => slope,
+        divisor => (x / divisor, y / divisor),
+    }
+}
+
+#[derive(Debug)]
+pub struct City {
+    antennas: HashMap<char, Vec<(usize, usize)>>,
+    width: usize,
+    height: usize,
+}
+
+impl City {
+    fn parse(raw: &str) -> Result<Self, GridError> {
+        let map = Grid::try_from_str_with(raw, |character| {
+            (character == '.' || character.is_ascii_alphanumeric()).then_some(character)
+        })?;
+
+        let mut antennas = HashMap::<_, Vec<_>>::new();
+
+        for (position, &character) in map.iter().filter(|(_, character)| **character != '.') {
+            antennas.entry(character).or_default().push(position);
+        }
+
+        Ok(Self {
+            antennas,
+            width: map.width(),
+            height: map.height(),
+        })
+    }
+
+    fn inline_antennas(
+        &self,
+        frequency: &char,
+        position: (usize, usize),
+    ) -> Vec<Vec<(usize, usize)>> {
+        let mut slopes = HashMap::<_, Vec<_>>::new();
+
+        if let Some(antennas) = self.antennas.get(frequency) {
+            let (x, y) = position;
+
+            for (antenna_x, antenna_y) in antennas {
+                let slope_x = *antenna_x as isize - x as isize;
+                let slope_y = *antenna_y as isize - y as isize;
+
+                let slope = reduce_slope((slope_x, slope_y));
+
+                slopes
+                    .entry(slope)
+                    .or_default()
+                    .push((*antenna_x, *antenna_y));
+            }
+        }
+
+        slopes
+            .into_values()
+            .filter(|antennas| antennas.len() > 1)
+            .collect()
+    }
+
+    fn antinodes_distance(&self) -> u32 {
+        let mut total_antinodes = 0;
+
+        width_height_2d_iter(self.width, self.height).for_each(|(x, y)| {
+            'frequency: for frequency in self.antennas.keys() {
+                let inline_antennas = self.inline_antennas(frequency, (x, y));
+
+                for inline_antenna_group in inline_antennas {
+                    for antennas in inline_antenna_group.into_iter().combinations(2) {
+                        let (antenna_a_x, _) = antennas[0];
+                        let (antenna_b_x, _) = antennas[1];
+
+                        let distance_a_x = antenna_a_x.abs_diff(x);
+                        let distance_b_x = antenna_b_x.abs_diff(x);
+
+                        let min_distance = distance_a_x.min(distance_b_x);
+                        let max_distance = distance_a_x.max(distance_b_x);
+
+                        if max_distance % min_distance == 0 && max_distance / min_distance == 2 {
+                            total_antinodes += 1;
+                            break 'frequency;
+                        }
+                    }
+                }
+            }
+        });
+
+        total_antinodes
+    }
+
+    fn antinodes(&self) -> u32 {
+        let mut antinode_set = self
+            .antennas
+            .values()
+            .flatten()
+            .cloned()
+            .collect::<HashSet<_>>();
+
+        width_height_2d_iter(self.width, self.height).for_each(|(x, y)| {
+            for frequency in self.antennas.keys() {
+                let inline_antennas = self.inline_antennas(frequency, (x, y));
+
+                if !inline_antennas.is_empty() {
+                    antinode_set.insert((x, y));
+                    break;
+                }
+            }
+        });
+
+        antinode_set.len() as u32
+    }
+}
+
+pub struct Day8;
+
+impl Solution for Day8 {
+    const YEAR: u16 = 2024;
+    const DAY: u8 = 8;
+
+    type Input = City;
+
+    fn parse(raw: &str) -> PuzzleResult<Self::Input> {
+        Ok(City::parse(raw)?)
+    }
+
+    fn part_one(input: &Self::Input) -> PuzzleResult<impl Display> {
+        Ok(input.antinodes_distance())
+    }
+
+    fn part_two(input: &Self::Input) -> PuzzleResult<impl Display> {
+        Ok(input.antinodes())
+    }
+}
+
+pub fn register(registry: &mut Registry) {
+    registry.register::<Day8>();
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE_ONE: &str = "............
+........0...
+.....0......
+.......0....
+....0.......
+......A.....
+............
+............
+........A...
+.........A..
+............
+............";
+
+    #[test]
+    fn reduce_slope() {
+        assert_eq!(super::reduce_slope((10, -20)), (1, -2));
+        assert_eq!(super::reduce_slope((0, -20)), (0, -1));
+        assert_eq!(super::reduce_slope((0, 0)), (0, 0));
+    }
+
+    #[test]
+    fn example_1_inline() {
+        let city = City::parse(EXAMPLE_ONE).unwrap();
+        let inlines = city.inline_antennas(&'A', (7, 7));
+        assert_eq!(inlines.len(), 1);
+        assert_eq!(inlines[0].len(), 2);
+        assert!(inlines[0].contains(&(8, 8)));
+        assert!(inlines[0].contains(&(9, 9)));
+    }
+
+    #[test]
+    fn example_1() {
+        let city = City::parse(EXAMPLE_ONE).unwrap();
+        assert_eq!(city.antinodes_distance(), 14);
+    }
+
+    #[test]
+    fn example_2() {
+        let city = City::parse(EXAMPLE_ONE).unwrap();
+        assert_eq!(city.antinodes(), 34);
+    }
+
+    #[test]
+    fn parse_error() {
+        let error = Day8::parse("..a.\n.#..").unwrap_err();
+        assert_eq!(error, PuzzleError::parse(2, 2, "unexpected '#'"));
+
+        let error = Day8::parse("..a.\n...").unwrap_err();
+        assert_eq!(error, PuzzleError::parse(2, 4, "row is 3 wide, expected 4"));
+    }
+}
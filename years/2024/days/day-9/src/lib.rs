@@ -0,0 +1,263 @@
+use std::fmt::Display;
+
+use common::*;
+
+#[derive(Debug, Eq, PartialEq, Clone)]
+pub enum FileSystemSegment {
+    File { id: usize, size: u8 },
+    FreeSpace(u8),
+}
+
+impl FileSystemSegment {
+    fn from_enumerated_size(value: (usize, u8)) -> Self {
+        let (index, segment_int) = value;
+
+        if index % 2 == 0 {
+            Self::File {
+                id: index / 2,
+                size: segment_int,
+            }
+        } else {
+            Self::FreeSpace(segment_int)
+        }
+    }
+
+    fn checksum(&self, index: &mut usize) -> Option<usize> {
+        match self {
+            Self::File { id, size } => {
+                let checksum = (*index..(*index + *size as usize))
+                    .map(|position| position * id)
+                    .sum();
+
+                *index += *size as usize;
+
+                Some(checksum)
+            }
+            Self::FreeSpace(amount) => {
+                *index += *amount as usize;
+                None
+            }
+        }
+    }
+
+    fn take(&mut self, amount: u8) -> Option<(usize, u8)> {
+        match self {
+            Self::File { id, size } => {
+                let current_size = *size;
+                *size = size.saturating_sub(amount);
+
+                Some((*id, current_size - *size))
+            }
+            Self::FreeSpace(_) => None,
+        }
+    }
+
+    fn is_empty(&self) -> bool {
+        match self {
+            Self::File { id: _, size } => *size == 0,
+            Self::FreeSpace(amount) => *amount == 0,
+        }
+    }
+}
+
+fn parse_segments(raw: &str) -> PuzzleResult<Vec<FileSystemSegment>> {
+    let disk_map = raw.trim_end();
+
+    if disk_map.is_empty() {
+        return Err(PuzzleError::parse(
+            1,
+            1,
+            "expected a digit, found end of input",
+        ));
+    }
+
+    disk_map
+        .chars()
+        .enumerate()
+        .map(|(index, character)| match character.to_digit(10) {
+            Some(size) => Ok(FileSystemSegment::from_enumerated_size((index, size as u8))),
+            None => Err(PuzzleError::parse(
+                1,
+                index + 1,
+                format!("expected a digit, found {:?}", character),
+            )),
+        })
+        .collect()
+}
+
+fn reallocate(segments: &[FileSystemSegment]) -> Vec<FileSystemSegment> {
+    let mut reallocated = Vec::with_capacity(segments.len());
+    let mut end_index = segments.len() - 1;
+    let mut last_element = segments[end_index].clone();
+
+    'segment: for (i, segment) in segments.iter().enumerate() {
+        if end_index == i {
+            break;
+        }
+
+        match segment {
+            FileSystemSegment::File { id: _, size: _ } => reallocated.push(segment.clone()),
+            FileSystemSegment::FreeSpace(mut amount) => {
+                while amount > 0 {
+                    if let Some((id, size)) = last_element.take(amount) {
+                        amount -= size;
+                        reallocated.push(FileSystemSegment::File { id, size });
+
+                        if last_element.is_empty() {
+                            end_index -= 1;
+                            last_element = segments[end_index].clone();
+
+                            if end_index == i {
+                                break 'segment;
+                            }
+                        }
+                    } else {
+                        end_index -= 1;
+                        last_element = segments[end_index].clone();
+
+                        if end_index == i {
+                            break 'segment;
+                        }
+                    }
+                }
+            }
+        }
+    }
+
+    reallocated.push(last_element);
+
+    reallocated
+}
+
+fn reallocate_strict(mut segments: Vec<FileSystemSegment>) -> Vec<FileSystemSegment> {
+    let mut i = segments.len();
+
+    while i > 2 {
+        i -= 1;
+
+        if let FileSystemSegment::File { id: _, size } = segments[i] {
+            let mut search_index = 1;
+            while search_index < i {
+                if let FileSystemSegment::FreeSpace(space) = &mut segments[search_index] {
+                    if *space < size {
+                        search_index += 1;
+                        continue;
+                    }
+
+                    let space_left = *space - size;
+
+                    if space_left > 0 {
+                        *space = size;
+                    }
+
+                    segments.swap(search_index, i);
+
+                    if space_left > 0 {
+                        segments.insert(search_index + 1, FileSystemSegment::FreeSpace(space_left));
+                    }
+
+                    break;
+                }
+
+                search_index += 1;
+            }
+        }
+    }
+
+    segments
+}
+
+fn process_checksums(segments: &[FileSystemSegment]) -> usize {
+    let mut index = 0;
+
+    segments
+        .iter()
+        .filter_map(|segment| segment.checksum(&mut index))
+        .sum()
+}
+
+fn part_one(segments: &[FileSystemSegment]) -> usize {
+    process_checksums(&reallocate(segments))
+}
+
+fn part_two(segments: Vec<FileSystemSegment>) -> usize {
+    process_checksums(&reallocate_strict(segments))
+}
+
+pub struct Day9;
+
+impl Solution for Day9 {
+    const YEAR: u16 = 2024;
+    const DAY: u8 = 9;
+
+    type Input = Vec<FileSystemSegment>;
+
+    fn parse(raw: &str) -> PuzzleResult<Self::Input> {
+        parse_segments(raw)
+    }
+
+    fn part_one(input: &Self::Input) -> PuzzleResult<impl Display> {
+        Ok(part_one(input))
+    }
+
+    fn part_two(input: &Self::Input) -> PuzzleResult<impl Display> {
+        Ok(part_two(input.clone()))
+    }
+}
+
+pub fn register(registry: &mut Registry) {
+    registry.register::<Day9>();
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE_ONE: &str = "2333133121414131402";
+
+    #[test]
+    fn segments() {
+        let mut segments = parse_segments("123").unwrap().into_iter();
+
+        assert_eq!(
+            segments.next(),
+            Some(FileSystemSegment::File { id: 0, size: 1 })
+        );
+
+        assert_eq!(segments.next(), Some(FileSystemSegment::FreeSpace(2)));
+
+        assert_eq!(
+            segments.next(),
+            Some(FileSystemSegment::File { id: 1, size: 3 })
+        );
+
+        assert_eq!(segments.next(), None);
+    }
+
+    #[test]
+    fn example_1() {
+        let segments = parse_segments(EXAMPLE_ONE).unwrap();
+        assert_eq!(part_one(&segments), 1928);
+    }
+
+    #[test]
+    fn example_2() {
+        let segments = parse_segments(EXAMPLE_ONE).unwrap();
+        assert_eq!(part_two(segments), 2858);
+    }
+
+    #[test]
+    fn parse_error() {
+        let error = Day9::parse("2333x33").unwrap_err();
+        assert_eq!(
+            error,
+            PuzzleError::parse(1, 5, "expected a digit, found 'x'")
+        );
+
+        let error = Day9::parse("\n").unwrap_err();
+        assert_eq!(
+            error,
+            PuzzleError::parse(1, 1, "expected a digit, found end of input")
+        );
+    }
+}
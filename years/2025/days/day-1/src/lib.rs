@@ -0,0 +1,273 @@
+use std::{
+    fmt::Display,
+    ops::{Add, AddAssign, Sub},
+    str::FromStr,
+};
+
+use anyhow::{Context, anyhow};
+use common::*;
+
+const START_ROTATION: u16 = 50;
+const FULL_ROTATION: u16 = 100;
+
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub struct RotationValue(u16);
+
+impl RotationValue {
+    const fn add_rotation(self, rhs: Self) -> (Self, u16) {
+        let sum = self.0 + rhs.0;
+
+        (Self(sum % FULL_ROTATION), sum / FULL_ROTATION)
+    }
+
+    fn sub_rotation(self, rhs: Self) -> (Self, u16) {
+        let diff = self.0.cast_signed() - rhs.0.cast_signed();
+        let first_zero = if self.0 == 0 { 0 } else { 100 };
+        let zeros = (first_zero - diff).cast_unsigned() / FULL_ROTATION;
+
+        (
+            Self(diff.rem_euclid(FULL_ROTATION.cast_signed()).cast_unsigned()),
+            zeros,
+        )
+    }
+}
+
+impl Default for RotationValue {
+    fn default() -> Self {
+        Self(START_ROTATION)
+    }
+}
+
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub enum Rotation {
+    Left(RotationValue),
+    Right(RotationValue),
+}
+
+impl FromStr for Rotation {
+    type Err = anyhow::Error;
+
+    fn from_str(s: &str) -> anyhow::Result<Self> {
+        let first = s.chars().next().context("Rotation is empty.")?;
+        let distance_raw = &s[1..];
+        let distance = distance_raw
+            .parse::<u16>()
+            .with_context(|| format!("Failed to parse rotation distance: {distance_raw}"))?;
+        let distance_clamped = distance % FULL_ROTATION;
+
+        match first {
+            'R' => Ok(Self::Right(RotationValue(distance_clamped))),
+            'L' => Ok(Self::Left(RotationValue(distance_clamped))),
+            _ => Err(anyhow!("Unsupported rotation direction: {first}")),
+        }
+    }
+}
+
+impl Add for RotationValue {
+    type Output = Self;
+
+    fn add(self, rhs: Self) -> Self {
+        self.add_rotation(rhs).0
+    }
+}
+
+impl Sub for RotationValue {
+    type Output = Self;
+
+    fn sub(self, rhs: Self) -> Self {
+        self.sub_rotation(rhs).0
+    }
+}
+
+impl Add<Rotation> for RotationValue {
+    type Output = Self;
+
+    fn add(self, rhs: Rotation) -> Self {
+        match rhs {
+            Rotation::Left(value) => self - value,
+            Rotation::Right(value) => self + value,
+        }
+    }
+}
+
+impl AddAssign<Rotation> for RotationValue {
+    fn add_assign(&mut self, rhs: Rotation) {
+        *self = *self + rhs
+    }
+}
+
+fn parse_input(input: &str) -> PuzzleResult<Vec<Rotation>> {
+    input
+        .lines()
+        .enumerate()
+        .map(|(index, line)| {
+            line.parse()
+                .map_err(|error| PuzzleError::parse(index + 1, 1, format!("{:#}", error)))
+        })
+        .collect()
+}
+
+fn part_one(rotations: &[Rotation]) -> usize {
+    let mut password = 0;
+    let mut current_rotation = RotationValue::default();
+
+    for &rotation in rotations {
+        current_rotation += rotation;
+
+        if current_rotation.0 == 0 {
+            password += 1;
+        }
+    }
+
+    password
+}
+
+fn part_two(rotations: &[Rotation]) -> usize {
+    let mut password = 0;
+    let mut current_rotation = RotationValue::default();
+
+    for &rotation in rotations {
+        let (new_rotation, zeros) = match rotation {
+            Rotation::Left(value) => current_rotation.sub_rotation(value),
+            Rotation::Right(value) => current_rotation.add_rotation(value),
+        };
+
+        current_rotation = new_rotation;
+        password += zeros as usize;
+    }
+
+    password
+}
+
+pub struct Day1;
+
+impl Solution for Day1 {
+    const YEAR: u16 = 2025;
+    const DAY: u8 = 1;
+
+    type Input = Vec<Rotation>;
+
+    fn parse(raw: &str) -> PuzzleResult<Self::Input> {
+        parse_input(raw)
+    }
+
+    fn part_one(input: &Self::Input) -> PuzzleResult<impl Display> {
+        Ok(part_one(input))
+    }
+
+    fn part_two(input: &Self::Input) -> PuzzleResult<impl Display> {
+        Ok(part_two(input))
+    }
+}
+
+pub fn register(registry: &mut Registry) {
+    registry.register::<Day1>();
+}
+
+example_tests!(Day1);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn rotation_left_0() {
+        let raw = "L0";
+        let parsed = raw.parse::<Rotation>().unwrap();
+        assert_eq!(parsed, Rotation::Left(RotationValue(0)));
+    }
+
+    #[test]
+    fn rotation_left_50() {
+        let raw = "L50";
+        let parsed = raw.parse::<Rotation>().unwrap();
+        assert_eq!(parsed, Rotation::Left(RotationValue(50)));
+    }
+
+    #[test]
+    fn rotation_left_100() {
+        let raw = "L100";
+        let parsed = raw.parse::<Rotation>().unwrap();
+        assert_eq!(parsed, Rotation::Left(RotationValue(0)));
+    }
+
+    #[test]
+    fn rotation_right_0() {
+        let raw = "R0";
+        let parsed = raw.parse::<Rotation>().unwrap();
+        assert_eq!(parsed, Rotation::Right(RotationValue(0)));
+    }
+
+    #[test]
+    fn rotation_right_50() {
+        let raw = "R50";
+        let parsed = raw.parse::<Rotation>().unwrap();
+        assert_eq!(parsed, Rotation::Right(RotationValue(50)));
+    }
+
+    #[test]
+    fn rotation_right_100() {
+        let raw = "R100";
+        let parsed = raw.parse::<Rotation>().unwrap();
+        assert_eq!(parsed, Rotation::Right(RotationValue(0)));
+    }
+
+    #[test]
+    fn rotation_add_overflow() {
+        let value = RotationValue(99);
+        let rotation = Rotation::Right(RotationValue(99));
+        assert_eq!(value + rotation, RotationValue(98));
+    }
+
+    #[test]
+    fn rotation_add_underflow() {
+        let value = RotationValue(0);
+        let rotation = Rotation::Left(RotationValue(99));
+        assert_eq!(value + rotation, RotationValue(1));
+    }
+
+    #[test]
+    fn parse_error_line() {
+        let error = parse_input("L68\nX30").unwrap_err();
+        assert_eq!(
+            error,
+            PuzzleError::parse(2, 1, "Unsupported rotation direction: X")
+        );
+    }
+
+    #[test]
+    fn rotation_add_zeros() {
+        let rotation = RotationValue(0);
+        let other = RotationValue(300);
+        let (sum, zeros) = rotation.add_rotation(other);
+        assert_eq!(sum, RotationValue(0));
+        assert_eq!(zeros, 3);
+    }
+
+    #[test]
+    fn rotation_sub_zeros() {
+        let rotation = RotationValue(10);
+        let other = RotationValue(10);
+        let (sum, zeros) = rotation.sub_rotation(other);
+        assert_eq!(sum, RotationValue(0));
+        assert_eq!(zeros, 1);
+    }
+
+    #[test]
+    fn rotation_sub_zeros_overflow() {
+        let rotation = RotationValue(0);
+        let other = RotationValue(300);
+        let (sum, zeros) = rotation.sub_rotation(other);
+        assert_eq!(sum, RotationValue(0));
+        assert_eq!(zeros, 3);
+    }
+
+    #[test]
+    fn rotation_add_full_turns() {
+        let rotation = RotationValue(50);
+        let other = RotationValue(1000);
+        let (sum, zeros) = rotation.add_rotation(other);
+        assert_eq!(sum, RotationValue(50));
+        assert_eq!(zeros, 10);
+    }
+}